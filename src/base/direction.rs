@@ -0,0 +1,18 @@
+/// The winding direction of a contour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContourDirection {
+    #[default]
+    CounterClockwise,
+    Clockwise,
+}
+
+impl ContourDirection {
+    /// The direction expected for holes when the outer contour has this direction.
+    #[inline]
+    pub fn reversed(self) -> Self {
+        match self {
+            ContourDirection::CounterClockwise => ContourDirection::Clockwise,
+            ContourDirection::Clockwise => ContourDirection::CounterClockwise,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use core::iter::FusedIterator;
+
+/// An iterator over the edges of a closed contour, including the closing edge.
+///
+/// The edge `i` goes from the point `i` to the point `i + 1`.
+#[derive(Debug, Clone)]
+pub struct Edges<'a, P> {
+    points: &'a [P],
+    index: usize,
+}
+
+/// An iterator over the corners of a closed contour as `(prev, cur, next)` triples.
+///
+/// The corner `i` is centered at the point `i`.
+#[derive(Debug, Clone)]
+pub struct Corners<'a, P> {
+    points: &'a [P],
+    index: usize,
+}
+
+pub trait ContourIter<P> {
+    fn edges(&self) -> Edges<'_, P>;
+    fn corners(&self) -> Corners<'_, P>;
+}
+
+impl<P: Copy> ContourIter<P> for [P] {
+    #[inline]
+    fn edges(&self) -> Edges<'_, P> {
+        Edges {
+            points: self,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    fn corners(&self) -> Corners<'_, P> {
+        Corners {
+            points: self,
+            index: 0,
+        }
+    }
+}
+
+impl<P: Copy> Iterator for Edges<'_, P> {
+    type Item = (P, P);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = *self.points.get(self.index)?;
+        self.index += 1;
+        let b = if let Some(&b) = self.points.get(self.index) {
+            b
+        } else {
+            self.points[0]
+        };
+        Some((a, b))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.points.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<P: Copy> ExactSizeIterator for Edges<'_, P> {}
+impl<P: Copy> FusedIterator for Edges<'_, P> {}
+
+impl<P: Copy> Iterator for Corners<'_, P> {
+    type Item = (P, P, P);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.points.len();
+        let cur = *self.points.get(self.index)?;
+        let prev = self.points[(self.index + n - 1) % n];
+        let next = self.points[(self.index + 1) % n];
+        self.index += 1;
+        Some((prev, cur, next))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.points.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<P: Copy> ExactSizeIterator for Corners<'_, P> {}
+impl<P: Copy> FusedIterator for Corners<'_, P> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::iter::ContourIter;
+    use crate::int_path;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+
+    #[test]
+    fn test_0() {
+        let contour = int_path![[0, 0], [1, 0], [1, 1],];
+        let p = |x, y| IntPoint::new(x, y);
+
+        let edges: Vec<_> = contour.edges().collect();
+        assert_eq!(
+            edges,
+            [(p(0, 0), p(1, 0)), (p(1, 0), p(1, 1)), (p(1, 1), p(0, 0))]
+        );
+
+        let corners: Vec<_> = contour.corners().collect();
+        assert_eq!(
+            corners,
+            [
+                (p(1, 1), p(0, 0), p(1, 0)),
+                (p(0, 0), p(1, 0), p(1, 1)),
+                (p(1, 0), p(1, 1), p(0, 0))
+            ]
+        );
+
+        assert_eq!(contour.edges().len(), 3);
+        assert_eq!(int_path![].edges().count(), 0);
+        assert_eq!(int_path![].corners().count(), 0);
+    }
+
+    #[test]
+    fn test_1() {
+        let path = [[0.0f32, 0.0], [1.0, 0.0], [0.0, 1.0]];
+        let length: f32 = path
+            .edges()
+            .map(|(a, b)| ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt())
+            .sum();
+        assert!((length - (2.0 + 2f32.sqrt())).abs() < 1e-6);
+    }
+}
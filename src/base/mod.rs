@@ -1 +1,3 @@
 pub mod data;
+pub mod direction;
+pub mod iter;
@@ -5,14 +5,22 @@ use alloc::vec::Vec;
 use core::ops::Range;
 use i_float::int::point::IntPoint;
 
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "crate::flat::layout::raw::RawFlatContoursBuffer")
+)]
 #[derive(Debug, Clone, Default)]
 pub struct FlatContoursBuffer {
     pub points: Vec<IntPoint>,
     pub ranges: Vec<Range<usize>>,
 }
 
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "crate::flat::layout::raw::RawFlatShapesBuffer")
+)]
 #[derive(Debug, Clone, Default)]
 pub struct FlatShapesBuffer {
     pub points: Vec<IntPoint>,
@@ -29,9 +37,10 @@ impl FlatContoursBuffer {
         }
     }
 
+    /// Checks if no contour has points, unused points left by the edits are ignored.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.points.is_empty()
+        self.ranges.iter().all(|range| range.is_empty())
     }
 
     #[inline]
@@ -137,9 +146,10 @@ impl FlatShapesBuffer {
         }
     }
 
+    /// Checks if no contour has points, unused points left by the edits are ignored.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.points.is_empty()
+        self.contour_ranges.iter().all(|range| range.is_empty())
     }
 
     #[inline]
@@ -0,0 +1,527 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::view::ShapeView;
+use crate::float::adapter::PathToFloat;
+use crate::int::count::PointsCount;
+use crate::int::shape::IntContour;
+use alloc::vec::Vec;
+use core::ops::Range;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::int::point::IntPoint;
+
+/// In-place editing.
+///
+/// The ranges are expected to follow the order of the points and not to overlap.
+/// Edits keep this order, removed or shrunk contours leave unused points behind.
+/// Such a buffer is still valid and serializable, `compact` only reclaims the memory.
+impl FlatContoursBuffer {
+    /// Removes the contour at `index`, its points stay in the buffer until `compact`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove_contour(&mut self, index: usize) {
+        self.ranges.remove(index);
+    }
+
+    /// Replaces the contour at `index`.
+    ///
+    /// A contour which fits into the old one is written in place,
+    /// a larger one is spliced into the points and the following ranges are shifted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn replace_contour(&mut self, index: usize, contour: &[IntPoint]) {
+        let range = self.ranges[index].clone();
+        let new_range = range.start..range.start + contour.len();
+        if contour.len() <= range.len() {
+            self.points[new_range.clone()].copy_from_slice(contour);
+        } else {
+            self.points.splice(range.clone(), contour.iter().copied());
+            shift_ranges(&mut self.ranges[index + 1..], range.len(), contour.len());
+        }
+        self.ranges[index] = new_range;
+    }
+
+    /// Keeps only the contours for which `f` returns `true`.
+    #[inline]
+    pub fn retain_contours<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&[IntPoint]) -> bool,
+    {
+        let points = &self.points;
+        self.ranges.retain(|range| f(&points[range.clone()]));
+    }
+
+    /// Appends all contours of `other`.
+    #[inline]
+    pub fn append(&mut self, other: &FlatContoursBuffer) {
+        let offset = self.points.len();
+        self.points.extend_from_slice(&other.points);
+        self.ranges.extend(
+            other
+                .ranges
+                .iter()
+                .map(|range| range.start + offset..range.end + offset),
+        );
+    }
+
+    /// Removes the points which are not referenced by any contour.
+    #[inline]
+    pub fn compact(&mut self) {
+        compact_points(&mut self.points, &mut self.ranges);
+    }
+}
+
+/// In-place editing.
+///
+/// The ranges are expected to follow the order of the points and not to overlap.
+/// Edits keep this order, removed or shrunk shapes leave unused points behind.
+/// Such a buffer is still valid and serializable, `compact` only reclaims the memory.
+impl FlatShapesBuffer {
+    /// Removes the shape at `index`, its points stay in the buffer until `compact`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_shape(&mut self, index: usize) {
+        let shape_range = self.shape_ranges.remove(index);
+        let count = shape_range.len();
+        self.contour_ranges.drain(shape_range);
+        shift_ranges(&mut self.shape_ranges[index..], count, 0);
+    }
+
+    /// Replaces the shape at `index`.
+    ///
+    /// A shape which fits into the points of the old one is written in place,
+    /// a larger one is spliced into the points and the following ranges are shifted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn replace_shape(&mut self, index: usize, shape: &[IntContour]) {
+        let shape_range = self.shape_ranges[index].clone();
+        let old_contours = &self.contour_ranges[shape_range.clone()];
+        let start = if let Some(first) = old_contours.first() {
+            first.start
+        } else {
+            self.contour_ranges[..shape_range.start]
+                .last()
+                .map_or(0, |range| range.end)
+        };
+        let end = old_contours.last().map_or(start, |range| range.end);
+
+        let new_len = shape.points_count();
+        let old_len = end - start;
+        if new_len <= old_len {
+            let mut offset = start;
+            for contour in shape.iter() {
+                self.points[offset..offset + contour.len()].copy_from_slice(contour);
+                offset += contour.len();
+            }
+        } else {
+            self.points.splice(start..end, shape.iter().flatten().copied());
+            let next_contour = shape_range.end;
+            shift_ranges(&mut self.contour_ranges[next_contour..], old_len, new_len);
+        }
+
+        let mut offset = start;
+        let ranges = shape.iter().map(|contour| {
+            let range = offset..offset + contour.len();
+            offset = range.end;
+            range
+        });
+        self.contour_ranges.splice(shape_range.clone(), ranges);
+
+        self.shape_ranges[index] = shape_range.start..shape_range.start + shape.len();
+        shift_ranges(
+            &mut self.shape_ranges[index + 1..],
+            shape_range.len(),
+            shape.len(),
+        );
+    }
+
+    /// Keeps only the shapes for which `f` returns `true`.
+    pub fn retain_shapes<F>(&mut self, mut f: F)
+    where
+        F: FnMut(ShapeView<'_>) -> bool,
+    {
+        let mut contours_len = 0;
+        let mut shapes_len = 0;
+        for shape_index in 0..self.shape_ranges.len() {
+            let shape_range = self.shape_ranges[shape_index].clone();
+            let view = ShapeView::new(&self.points, &self.contour_ranges[shape_range.clone()]);
+            if !f(view) {
+                continue;
+            }
+
+            let start = contours_len;
+            for contour_index in shape_range {
+                self.contour_ranges[contours_len] = self.contour_ranges[contour_index].clone();
+                contours_len += 1;
+            }
+            self.shape_ranges[shapes_len] = start..contours_len;
+            shapes_len += 1;
+        }
+
+        self.contour_ranges.truncate(contours_len);
+        self.shape_ranges.truncate(shapes_len);
+    }
+
+    /// Appends all shapes of `other`.
+    pub fn append(&mut self, other: &FlatShapesBuffer) {
+        let points_offset = self.points.len();
+        let contours_offset = self.contour_ranges.len();
+        self.points.extend_from_slice(&other.points);
+        self.contour_ranges.extend(
+            other
+                .contour_ranges
+                .iter()
+                .map(|range| range.start + points_offset..range.end + points_offset),
+        );
+        self.shape_ranges.extend(
+            other
+                .shape_ranges
+                .iter()
+                .map(|range| range.start + contours_offset..range.end + contours_offset),
+        );
+    }
+
+    /// Removes the points which are not referenced by any contour.
+    #[inline]
+    pub fn compact(&mut self) {
+        compact_points(&mut self.points, &mut self.contour_ranges);
+    }
+}
+
+/// The result of rewriting a single contour.
+pub(crate) enum ContourEdit<P> {
+    Keep,
+    /// The new points, never longer than the original contour.
+    Replace(Vec<P>),
+    /// The contour was rewritten in place into its first `len` points.
+    Shrink(usize),
+    Remove,
+}
+
+impl<P> ContourEdit<P> {
+    #[inline]
+    fn is_changed(&self, len: usize) -> bool {
+        match *self {
+            ContourEdit::Keep => false,
+            ContourEdit::Shrink(new_len) => new_len < len,
+            _ => true,
+        }
+    }
+}
+
+impl ContourEdit<IntPoint> {
+    /// Converts the edit of the int copy `contour` to float points,
+    /// a kept contour keeps its original points.
+    #[inline]
+    pub(crate) fn into_float<P: FloatPointCompatible>(
+        self,
+        contour: &[IntPoint],
+        adapter: &FloatPointAdapter<P>,
+    ) -> ContourEdit<P> {
+        match self {
+            ContourEdit::Keep => ContourEdit::Keep,
+            ContourEdit::Replace(contour) => ContourEdit::Replace(contour.to_float(adapter)),
+            ContourEdit::Shrink(len) => ContourEdit::Replace(contour[..len].to_float(adapter)),
+            ContourEdit::Remove => ContourEdit::Remove,
+        }
+    }
+}
+
+/// Rewrites every contour in place, the points are compacted and removed contours are dropped.
+/// `edit` receives the contour index and the points.
+///
+/// # Returns
+///
+/// - `true` if any contour was replaced or removed.
+pub(crate) fn rewrite_contours<P, F>(points: &mut Vec<P>, ranges: &mut Vec<Range<usize>>, mut edit: F) -> bool
+where
+    P: Copy,
+    F: FnMut(usize, &mut [P]) -> ContourEdit<P>,
+{
+    let mut changed = false;
+    let mut points_len = 0;
+    let mut contours_len = 0;
+    for contour_index in 0..ranges.len() {
+        let range = ranges[contour_index].clone();
+        let contour_edit = edit(contour_index, &mut points[range.clone()]);
+        changed |= contour_edit.is_changed(range.len());
+        let len = if let Some(len) = write_contour(points, range, points_len, contour_edit) {
+            len
+        } else {
+            continue;
+        };
+        ranges[contours_len] = points_len..points_len + len;
+        points_len += len;
+        contours_len += 1;
+    }
+
+    points.truncate(points_len);
+    ranges.truncate(contours_len);
+
+    changed
+}
+
+/// Rewrites every contour of every shape in place, the points are compacted.
+/// Removed holes are dropped, a shape is dropped if its outer contour is removed.
+/// `edit` receives the shape index, the contour index inside the shape and the points,
+/// the holes of a dropped shape are skipped.
+///
+/// # Returns
+///
+/// - `true` if any contour was replaced or removed.
+pub(crate) fn rewrite_shapes<P, F>(
+    points: &mut Vec<P>,
+    contour_ranges: &mut Vec<Range<usize>>,
+    shape_ranges: &mut Vec<Range<usize>>,
+    mut edit: F,
+) -> bool
+where
+    P: Copy,
+    F: FnMut(usize, usize, &mut [P]) -> ContourEdit<P>,
+{
+    let mut changed = false;
+    let mut points_len = 0;
+    let mut contours_len = 0;
+    let mut shapes_len = 0;
+    for shape_index in 0..shape_ranges.len() {
+        let shape_range = shape_ranges[shape_index].clone();
+        let shape_points_start = points_len;
+        let shape_contours_start = contours_len;
+        let mut is_removed = false;
+
+        for contour_index in shape_range.clone() {
+            let range = contour_ranges[contour_index].clone();
+            let contour_edit = edit(
+                shape_index,
+                contour_index - shape_range.start,
+                &mut points[range.clone()],
+            );
+            changed |= contour_edit.is_changed(range.len());
+            let len = if let Some(len) = write_contour(points, range, points_len, contour_edit) {
+                len
+            } else if contour_index == shape_range.start {
+                // the outer contour is degenerate, the whole shape is dropped
+                is_removed = true;
+                break;
+            } else {
+                continue;
+            };
+            contour_ranges[contours_len] = points_len..points_len + len;
+            points_len += len;
+            contours_len += 1;
+        }
+
+        if is_removed {
+            points_len = shape_points_start;
+            contours_len = shape_contours_start;
+            continue;
+        }
+
+        shape_ranges[shapes_len] = shape_contours_start..contours_len;
+        shapes_len += 1;
+    }
+
+    points.truncate(points_len);
+    contour_ranges.truncate(contours_len);
+    shape_ranges.truncate(shapes_len);
+
+    changed
+}
+
+/// Writes the edited contour at `offset`, which never exceeds `range.start`.
+///
+/// # Returns
+///
+/// - `Some(len)` the length of the written contour.
+/// - `None` if the contour is removed.
+#[inline]
+fn write_contour<P: Copy>(
+    points: &mut [P],
+    range: Range<usize>,
+    offset: usize,
+    edit: ContourEdit<P>,
+) -> Option<usize> {
+    match edit {
+        ContourEdit::Keep => {
+            let len = range.len();
+            points.copy_within(range, offset);
+            Some(len)
+        }
+        ContourEdit::Replace(contour) => {
+            debug_assert!(contour.len() <= range.len());
+            points[offset..offset + contour.len()].copy_from_slice(&contour);
+            Some(contour.len())
+        }
+        ContourEdit::Shrink(len) => {
+            debug_assert!(len <= range.len());
+            points.copy_within(range.start..range.start + len, offset);
+            Some(len)
+        }
+        ContourEdit::Remove => None,
+    }
+}
+
+/// Shifts the ranges which follow a region of `old_len` elements replaced by `new_len` elements.
+#[inline]
+fn shift_ranges(ranges: &mut [Range<usize>], old_len: usize, new_len: usize) {
+    if new_len == old_len {
+        return;
+    }
+    for range in ranges.iter_mut() {
+        *range = range.start + new_len - old_len..range.end + new_len - old_len;
+    }
+}
+
+#[inline]
+fn compact_points(points: &mut Vec<IntPoint>, ranges: &mut [Range<usize>]) {
+    let mut offset = 0;
+    for range in ranges.iter_mut() {
+        let len = range.len();
+        points.copy_within(range.clone(), offset);
+        *range = offset..offset + len;
+        offset += len;
+    }
+    points.truncate(offset);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::flat::float::FloatFlatContoursBuffer;
+    use crate::float::adapter::{BufferToFloat, BufferToInt};
+    use crate::int::shape::IntShapes;
+    use crate::{int_path, int_shape, int_shapes};
+    use alloc::vec;
+    use i_float::adapter::FloatPointAdapter;
+    use i_float::float::rect::FloatRect;
+
+    fn shapes() -> IntShapes {
+        int_shapes![
+            [
+                [[0, 0], [0, 4], [4, 4], [4, 0],],
+                [[1, 1], [2, 1], [2, 2], [1, 2],],
+            ],
+            [[[10, 0], [10, 1], [11, 0],],],
+            [[[20, 0], [20, 1], [21, 0],],],
+        ]
+    }
+
+    #[test]
+    fn test_0() {
+        let mut buffer = FlatContoursBuffer::default();
+        buffer.set_with_shapes(&shapes());
+
+        buffer.remove_contour(1);
+        assert_eq!(buffer.ranges.len(), 3);
+        assert_eq!(buffer.points.len(), 14);
+
+        let small = int_path![[5, 5], [5, 6], [6, 5],];
+        buffer.replace_contour(0, &small);
+        assert_eq!(buffer.contour(0), small.as_slice());
+
+        let large = int_path![[0, 0], [0, 1], [1, 2], [2, 1], [1, 0],];
+        buffer.replace_contour(1, &large);
+        assert_eq!(buffer.contour(1), large.as_slice());
+        assert_eq!(buffer.contour(2), shapes()[2][0].as_slice());
+
+        buffer.retain_contours(|contour| contour.len() == 3);
+        buffer.compact();
+        assert_eq!(buffer.points.len(), 6);
+        assert_eq!(buffer.to_contours(), vec![small, shapes()[2][0].clone()]);
+    }
+
+    #[test]
+    fn test_1() {
+        let shapes = shapes();
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        buffer.remove_shape(0);
+        assert_eq!(buffer.to_shapes(), shapes[1..]);
+
+        buffer.set_with_shapes(&shapes);
+        let small = int_shape![[[5, 5], [5, 6], [6, 5],],];
+        buffer.replace_shape(0, &small);
+        assert_eq!(
+            buffer.to_shapes(),
+            [small.clone(), shapes[1].clone(), shapes[2].clone()]
+        );
+
+        let large = int_shape![
+            [[0, 0], [0, 9], [9, 9], [9, 0],],
+            [[1, 1], [2, 1], [2, 2],],
+            [[5, 5], [6, 5], [6, 6],],
+        ];
+        buffer.replace_shape(1, &large);
+        assert_eq!(
+            buffer.to_shapes(),
+            [small.clone(), large.clone(), shapes[2].clone()]
+        );
+
+        assert!(buffer.validate_layout().is_ok());
+        buffer.compact();
+        assert!(buffer.validate_layout().is_ok());
+        assert_eq!(buffer.points.len(), 3 + 10 + 3);
+        assert_eq!(buffer.to_shapes(), [small, large, shapes[2].clone()]);
+    }
+
+    #[test]
+    fn test_2() {
+        let shapes = shapes();
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let mut other = FlatShapesBuffer::default();
+        other.set_with_shapes(&shapes[..2]);
+        buffer.append(&other);
+        assert_eq!(buffer.shape_ranges.len(), 5);
+
+        buffer.retain_shapes(|shape| shape.len() == 1);
+        assert_eq!(
+            buffer.to_shapes(),
+            [shapes[1].clone(), shapes[2].clone(), shapes[1].clone()]
+        );
+
+        buffer.compact();
+        assert_eq!(buffer.points.len(), 9);
+        assert_eq!(
+            buffer.to_shapes(),
+            [shapes[1].clone(), shapes[2].clone(), shapes[1].clone()]
+        );
+    }
+
+    #[test]
+    fn test_3() {
+        // every edit keeps a valid layout without compact
+        let mut buffer = FlatContoursBuffer::default();
+        buffer.set_with_shapes(&shapes());
+        buffer.replace_contour(0, &int_path![[5, 5], [5, 6], [6, 5],]);
+        assert!(buffer.validate_layout().is_ok());
+        buffer.retain_contours(|contour| contour[0].x < 20);
+        assert!(buffer.validate_layout().is_ok());
+
+        // only the points of the contours are converted
+        let adapter = FloatPointAdapter::new(FloatRect::new(0.0, 21.0, 0.0, 21.0));
+        let mut float: FloatFlatContoursBuffer<[f64; 2]> = buffer.to_float(&adapter);
+        assert_eq!(float.points.len(), 3 + 4 + 3);
+        assert!(float.validate_layout().is_ok());
+
+        // a far away unused point is never converted back
+        float.points.push([1e12, 1e12]);
+        assert_eq!(float.to_int(&adapter).to_contours(), buffer.to_contours());
+
+        while !buffer.ranges.is_empty() {
+            buffer.remove_contour(0);
+        }
+        assert!(buffer.is_empty());
+        assert!(!buffer.points.is_empty());
+    }
+}
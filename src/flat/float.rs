@@ -4,14 +4,28 @@ use alloc::vec::Vec;
 use core::ops::Range;
 use i_float::float::compatible::FloatPointCompatible;
 
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "crate::flat::layout::raw::RawFloatFlatContoursBuffer<P>",
+        bound(deserialize = "P: serde::Deserialize<'de>")
+    )
+)]
 #[derive(Debug, Clone, Default)]
 pub struct FloatFlatContoursBuffer<P> {
     pub points: Vec<P>,
     pub ranges: Vec<Range<usize>>,
 }
 
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "crate::flat::layout::raw::RawFloatFlatShapesBuffer<P>",
+        bound(deserialize = "P: serde::Deserialize<'de>")
+    )
+)]
 #[derive(Debug, Clone, Default)]
 pub struct FloatFlatShapesBuffer<P> {
     pub points: Vec<P>,
@@ -28,9 +42,10 @@ impl<P> FloatFlatContoursBuffer<P> {
         }
     }
 
+    /// Checks if no contour has points, unused points left by the edits are ignored.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.points.is_empty()
+        self.ranges.iter().all(|range| range.is_empty())
     }
 
     #[inline]
@@ -143,9 +158,10 @@ impl<P> FloatFlatShapesBuffer<P> {
         }
     }
 
+    /// Checks if no contour has points, unused points left by the edits are ignored.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.points.is_empty()
+        self.contour_ranges.iter().all(|range| range.is_empty())
     }
 
     #[inline]
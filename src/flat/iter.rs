@@ -0,0 +1,179 @@
+use crate::base::iter::ContourIter;
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use core::ops::Range;
+use i_float::int::point::IntPoint;
+
+impl FlatContoursBuffer {
+    /// Iterates over the edges of every contour as `(contour_index, (a, b))`.
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = (usize, (IntPoint, IntPoint))> + '_ {
+        contour_edges(&self.points, &self.ranges)
+    }
+
+    /// Iterates over the corners of every contour as `(contour_index, (prev, cur, next))`.
+    #[inline]
+    pub fn corners(&self) -> impl Iterator<Item = (usize, (IntPoint, IntPoint, IntPoint))> + '_ {
+        contour_corners(&self.points, &self.ranges)
+    }
+}
+
+impl FlatShapesBuffer {
+    /// Iterates over the edges of every contour as `(shape_index, contour_index, (a, b))`,
+    /// the contour index is local to the shape.
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, (IntPoint, IntPoint))> + '_ {
+        shape_edges(&self.points, &self.contour_ranges, &self.shape_ranges)
+    }
+
+    /// Iterates over the corners of every contour as `(shape_index, contour_index, (prev, cur, next))`,
+    /// the contour index is local to the shape.
+    #[inline]
+    pub fn corners(&self) -> impl Iterator<Item = (usize, usize, (IntPoint, IntPoint, IntPoint))> + '_ {
+        shape_corners(&self.points, &self.contour_ranges, &self.shape_ranges)
+    }
+}
+
+impl<P: Copy> FloatFlatContoursBuffer<P> {
+    /// Iterates over the edges of every contour as `(contour_index, (a, b))`.
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = (usize, (P, P))> + '_ {
+        contour_edges(&self.points, &self.ranges)
+    }
+
+    /// Iterates over the corners of every contour as `(contour_index, (prev, cur, next))`.
+    #[inline]
+    pub fn corners(&self) -> impl Iterator<Item = (usize, (P, P, P))> + '_ {
+        contour_corners(&self.points, &self.ranges)
+    }
+}
+
+impl<P: Copy> FloatFlatShapesBuffer<P> {
+    /// Iterates over the edges of every contour as `(shape_index, contour_index, (a, b))`,
+    /// the contour index is local to the shape.
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, (P, P))> + '_ {
+        shape_edges(&self.points, &self.contour_ranges, &self.shape_ranges)
+    }
+
+    /// Iterates over the corners of every contour as `(shape_index, contour_index, (prev, cur, next))`,
+    /// the contour index is local to the shape.
+    #[inline]
+    pub fn corners(&self) -> impl Iterator<Item = (usize, usize, (P, P, P))> + '_ {
+        shape_corners(&self.points, &self.contour_ranges, &self.shape_ranges)
+    }
+}
+
+#[inline]
+fn contour_edges<'a, P: Copy>(
+    points: &'a [P],
+    ranges: &'a [Range<usize>],
+) -> impl Iterator<Item = (usize, (P, P))> + 'a {
+    ranges
+        .iter()
+        .enumerate()
+        .flat_map(move |(index, range)| points[range.clone()].edges().map(move |edge| (index, edge)))
+}
+
+#[inline]
+fn contour_corners<'a, P: Copy>(
+    points: &'a [P],
+    ranges: &'a [Range<usize>],
+) -> impl Iterator<Item = (usize, (P, P, P))> + 'a {
+    ranges
+        .iter()
+        .enumerate()
+        .flat_map(move |(index, range)| points[range.clone()].corners().map(move |corner| (index, corner)))
+}
+
+#[inline]
+fn shape_edges<'a, P: Copy>(
+    points: &'a [P],
+    contour_ranges: &'a [Range<usize>],
+    shape_ranges: &'a [Range<usize>],
+) -> impl Iterator<Item = (usize, usize, (P, P))> + 'a {
+    shape_ranges
+        .iter()
+        .enumerate()
+        .flat_map(move |(shape, shape_range)| {
+            contour_edges(points, &contour_ranges[shape_range.clone()])
+                .map(move |(contour, edge)| (shape, contour, edge))
+        })
+}
+
+#[inline]
+fn shape_corners<'a, P: Copy>(
+    points: &'a [P],
+    contour_ranges: &'a [Range<usize>],
+    shape_ranges: &'a [Range<usize>],
+) -> impl Iterator<Item = (usize, usize, (P, P, P))> + 'a {
+    shape_ranges
+        .iter()
+        .enumerate()
+        .flat_map(move |(shape, shape_range)| {
+            contour_corners(points, &contour_ranges[shape_range.clone()])
+                .map(move |(contour, corner)| (shape, contour, corner))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::flat::float::FloatFlatShapesBuffer;
+    use crate::{int_shape, int_shapes, paths};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_0() {
+        let shapes = int_shapes![
+            [[[0, 0], [4, 0], [0, 4],], [[1, 1], [1, 2], [2, 1],],],
+            [[[5, 5], [6, 5], [6, 6], [5, 6],],],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let indices: Vec<_> = buffer.edges().map(|(s, c, _)| (s, c)).collect();
+        assert_eq!(
+            indices,
+            [
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 1),
+                (0, 1),
+                (0, 1),
+                (1, 0),
+                (1, 0),
+                (1, 0),
+                (1, 0)
+            ]
+        );
+
+        let (_, _, last) = buffer.edges().last().unwrap();
+        assert_eq!(last, (shapes[1][0][3], shapes[1][0][0]));
+
+        let (shape, contour, corner) = buffer.corners().nth(3).unwrap();
+        assert_eq!((shape, contour), (0, 1));
+        assert_eq!(corner, (shapes[0][1][2], shapes[0][1][0], shapes[0][1][1]));
+
+        let mut contours = FlatContoursBuffer::default();
+        contours.set_with_shape(&int_shape![[[0, 0], [1, 0], [0, 1],], [[2, 2], [3, 2], [2, 3],],]);
+        assert_eq!(contours.edges().filter(|(c, _)| *c == 1).count(), 3);
+        assert_eq!(contours.corners().count(), 6);
+    }
+
+    #[test]
+    fn test_1() {
+        let shape = paths![
+            [[0.0f64, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            [[0.2, 0.2], [0.2, 0.4], [0.4, 0.2]],
+        ];
+        let mut buffer = FloatFlatShapesBuffer::default();
+        buffer.set_with_resource(&shape);
+
+        assert_eq!(buffer.edges().count(), 6);
+        let (_, contour, (a, b)) = buffer.edges().nth(5).unwrap();
+        assert_eq!((contour, a, b), (1, [0.4, 0.2], [0.2, 0.2]));
+    }
+}
@@ -0,0 +1,414 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use i_float::int::point::IntPoint;
+
+/// A broken range layout of a flat buffer.
+///
+/// A valid layout has:
+/// - contour ranges which follow the order of the points and do not overlap,
+///   the points between or after them are unused, as left by the in-place edits;
+/// - shape ranges which split all contour ranges into consecutive groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The contour range has `start > end`.
+    ReversedRange { contour: usize },
+    /// The contour range ends after the last point.
+    RangeOutOfBounds { contour: usize, points: usize },
+    /// The contour range starts before the end of the previous one.
+    OverlappingRange { contour: usize },
+    /// The shape range does not start where the previous one ends or has `start > end`.
+    NonContiguousShape { shape: usize },
+    /// The shape ranges do not cover all contour ranges.
+    UncoveredContours { covered: usize, contours: usize },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            LayoutError::ReversedRange { contour } => {
+                write!(f, "contour range {contour} has start greater than end")
+            }
+            LayoutError::RangeOutOfBounds { contour, points } => {
+                write!(f, "contour range {contour} exceeds {points} points")
+            }
+            LayoutError::OverlappingRange { contour } => {
+                write!(f, "contour range {contour} overlaps or precedes the previous one")
+            }
+            LayoutError::NonContiguousShape { shape } => {
+                write!(f, "shape range {shape} does not continue the previous one")
+            }
+            LayoutError::UncoveredContours { covered, contours } => {
+                write!(f, "shape ranges cover {covered} of {contours} contours")
+            }
+        }
+    }
+}
+
+impl core::error::Error for LayoutError {}
+
+impl FlatContoursBuffer {
+    /// Creates a buffer from raw parts.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(FlatContoursBuffer)` if the ranges form a valid layout.
+    /// - `Err(LayoutError)` describing the first broken range otherwise.
+    #[inline]
+    pub fn try_from_parts(points: Vec<IntPoint>, ranges: Vec<Range<usize>>) -> Result<Self, LayoutError> {
+        let buffer = Self { points, ranges };
+        buffer.validate_layout()?;
+        Ok(buffer)
+    }
+
+    /// Checks that the ranges are ordered, do not overlap and stay inside the points.
+    #[inline]
+    pub fn validate_layout(&self) -> Result<(), LayoutError> {
+        validate_contours(self.points.len(), &self.ranges)
+    }
+}
+
+impl FlatShapesBuffer {
+    /// Creates a buffer from raw parts.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(FlatShapesBuffer)` if the ranges form a valid layout.
+    /// - `Err(LayoutError)` describing the first broken range otherwise.
+    #[inline]
+    pub fn try_from_parts(
+        points: Vec<IntPoint>,
+        contour_ranges: Vec<Range<usize>>,
+        shape_ranges: Vec<Range<usize>>,
+    ) -> Result<Self, LayoutError> {
+        let buffer = Self {
+            points,
+            contour_ranges,
+            shape_ranges,
+        };
+        buffer.validate_layout()?;
+        Ok(buffer)
+    }
+
+    /// Checks that the contour ranges are ordered, do not overlap and stay inside the points,
+    /// and that the shape ranges split them into consecutive groups.
+    #[inline]
+    pub fn validate_layout(&self) -> Result<(), LayoutError> {
+        validate_contours(self.points.len(), &self.contour_ranges)?;
+        validate_shapes(self.contour_ranges.len(), &self.shape_ranges)
+    }
+}
+
+impl<P> FloatFlatContoursBuffer<P> {
+    /// Creates a buffer from raw parts.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(FloatFlatContoursBuffer)` if the ranges form a valid layout.
+    /// - `Err(LayoutError)` describing the first broken range otherwise.
+    #[inline]
+    pub fn try_from_parts(points: Vec<P>, ranges: Vec<Range<usize>>) -> Result<Self, LayoutError> {
+        let buffer = Self { points, ranges };
+        buffer.validate_layout()?;
+        Ok(buffer)
+    }
+
+    /// Checks that the ranges are ordered, do not overlap and stay inside the points.
+    #[inline]
+    pub fn validate_layout(&self) -> Result<(), LayoutError> {
+        validate_contours(self.points.len(), &self.ranges)
+    }
+}
+
+impl<P> FloatFlatShapesBuffer<P> {
+    /// Creates a buffer from raw parts.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(FloatFlatShapesBuffer)` if the ranges form a valid layout.
+    /// - `Err(LayoutError)` describing the first broken range otherwise.
+    #[inline]
+    pub fn try_from_parts(
+        points: Vec<P>,
+        contour_ranges: Vec<Range<usize>>,
+        shape_ranges: Vec<Range<usize>>,
+    ) -> Result<Self, LayoutError> {
+        let buffer = Self {
+            points,
+            contour_ranges,
+            shape_ranges,
+        };
+        buffer.validate_layout()?;
+        Ok(buffer)
+    }
+
+    /// Checks that the contour ranges are ordered, do not overlap and stay inside the points,
+    /// and that the shape ranges split them into consecutive groups.
+    #[inline]
+    pub fn validate_layout(&self) -> Result<(), LayoutError> {
+        validate_contours(self.points.len(), &self.contour_ranges)?;
+        validate_shapes(self.contour_ranges.len(), &self.shape_ranges)
+    }
+}
+
+fn validate_contours(points: usize, ranges: &[Range<usize>]) -> Result<(), LayoutError> {
+    let mut prev_end = 0;
+    for (contour, range) in ranges.iter().enumerate() {
+        if range.start > range.end {
+            return Err(LayoutError::ReversedRange { contour });
+        }
+        if range.end > points {
+            return Err(LayoutError::RangeOutOfBounds { contour, points });
+        }
+        if range.start < prev_end {
+            return Err(LayoutError::OverlappingRange { contour });
+        }
+        prev_end = range.end;
+    }
+    Ok(())
+}
+
+fn validate_shapes(contours: usize, ranges: &[Range<usize>]) -> Result<(), LayoutError> {
+    let mut prev_end = 0;
+    for (shape, range) in ranges.iter().enumerate() {
+        if range.start != prev_end || range.start > range.end {
+            return Err(LayoutError::NonContiguousShape { shape });
+        }
+        prev_end = range.end;
+    }
+    if prev_end != contours {
+        return Err(LayoutError::UncoveredContours {
+            covered: prev_end,
+            contours,
+        });
+    }
+    Ok(())
+}
+
+/// Unchecked serde representations, deserialized buffers are converted with `try_from_parts`.
+#[cfg(feature = "serde")]
+pub(crate) mod raw {
+    use super::LayoutError;
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+    use alloc::vec::Vec;
+    use core::ops::Range;
+    use i_float::int::point::IntPoint;
+
+    #[derive(serde::Deserialize)]
+    pub(crate) struct RawFlatContoursBuffer {
+        points: Vec<IntPoint>,
+        ranges: Vec<Range<usize>>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub(crate) struct RawFlatShapesBuffer {
+        points: Vec<IntPoint>,
+        contour_ranges: Vec<Range<usize>>,
+        shape_ranges: Vec<Range<usize>>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub(crate) struct RawFloatFlatContoursBuffer<P> {
+        points: Vec<P>,
+        ranges: Vec<Range<usize>>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub(crate) struct RawFloatFlatShapesBuffer<P> {
+        points: Vec<P>,
+        contour_ranges: Vec<Range<usize>>,
+        shape_ranges: Vec<Range<usize>>,
+    }
+
+    impl TryFrom<RawFlatContoursBuffer> for FlatContoursBuffer {
+        type Error = LayoutError;
+
+        #[inline]
+        fn try_from(raw: RawFlatContoursBuffer) -> Result<Self, Self::Error> {
+            Self::try_from_parts(raw.points, raw.ranges)
+        }
+    }
+
+    impl TryFrom<RawFlatShapesBuffer> for FlatShapesBuffer {
+        type Error = LayoutError;
+
+        #[inline]
+        fn try_from(raw: RawFlatShapesBuffer) -> Result<Self, Self::Error> {
+            Self::try_from_parts(raw.points, raw.contour_ranges, raw.shape_ranges)
+        }
+    }
+
+    impl<P> TryFrom<RawFloatFlatContoursBuffer<P>> for FloatFlatContoursBuffer<P> {
+        type Error = LayoutError;
+
+        #[inline]
+        fn try_from(raw: RawFloatFlatContoursBuffer<P>) -> Result<Self, Self::Error> {
+            Self::try_from_parts(raw.points, raw.ranges)
+        }
+    }
+
+    impl<P> TryFrom<RawFloatFlatShapesBuffer<P>> for FloatFlatShapesBuffer<P> {
+        type Error = LayoutError;
+
+        #[inline]
+        fn try_from(raw: RawFloatFlatShapesBuffer<P>) -> Result<Self, Self::Error> {
+            Self::try_from_parts(raw.points, raw.contour_ranges, raw.shape_ranges)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::flat::float::FloatFlatShapesBuffer;
+    use crate::flat::layout::LayoutError;
+    use crate::{int_path, int_shapes};
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn test_0() {
+        let points = int_path![[0, 0], [1, 0], [1, 1], [0, 1], [2, 2], [3, 2], [3, 3],];
+
+        assert!(FlatContoursBuffer::try_from_parts(points.clone(), vec![0..4, 4..7]).is_ok());
+
+        // unused points between and after the contours
+        assert!(FlatContoursBuffer::try_from_parts(points.clone(), vec![0..3, 4..6]).is_ok());
+        assert!(FlatContoursBuffer::try_from_parts(points.clone(), vec![]).is_ok());
+
+        assert_eq!(
+            FlatContoursBuffer::try_from_parts(points.clone(), vec![0..4, 4..8]).unwrap_err(),
+            LayoutError::RangeOutOfBounds {
+                contour: 1,
+                points: 7
+            }
+        );
+        assert_eq!(
+            FlatContoursBuffer::try_from_parts(points.clone(), vec![0..4, 3..7]).unwrap_err(),
+            LayoutError::OverlappingRange { contour: 1 }
+        );
+        let (start, end) = (6, 5);
+        assert_eq!(
+            FlatContoursBuffer::try_from_parts(points, vec![0..4, start..end]).unwrap_err(),
+            LayoutError::ReversedRange { contour: 1 }
+        );
+    }
+
+    #[test]
+    fn test_1() {
+        let shapes = int_shapes![
+            [[[0, 0], [1, 0], [0, 1],], [[2, 2], [3, 2], [2, 3],],],
+            [[[5, 5], [6, 5], [5, 6],],],
+        ];
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+        assert!(buffer.validate_layout().is_ok());
+
+        buffer.shape_ranges[1] = 2..2;
+        let error = buffer.validate_layout().unwrap_err();
+        assert_eq!(
+            error,
+            LayoutError::UncoveredContours {
+                covered: 2,
+                contours: 3
+            }
+        );
+        assert_eq!(error.to_string(), "shape ranges cover 2 of 3 contours");
+
+        buffer.shape_ranges[1] = 1..3;
+        assert_eq!(
+            buffer.validate_layout().unwrap_err(),
+            LayoutError::NonContiguousShape { shape: 1 }
+        );
+
+        let float =
+            FloatFlatShapesBuffer::try_from_parts(vec![[0.0f64, 0.0]; 6], vec![0..3, 3..6], vec![0..1, 1..2]);
+        assert!(float.is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use alloc::string::String;
+        use serde_json::{Value, json};
+
+        let shapes = int_shapes![
+            [[[0, 0], [1, 0], [0, 1],], [[2, 2], [3, 2], [2, 3],],],
+            [[[5, 5], [6, 5], [5, 6],],],
+        ];
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let text = serde_json::to_string(&buffer).unwrap();
+        let restored: FlatShapesBuffer = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.to_shapes(), shapes);
+
+        let broken = |edit: &dyn Fn(&mut Value)| -> String {
+            let mut value: Value = serde_json::from_str(&text).unwrap();
+            edit(&mut value);
+            serde_json::from_value::<FlatShapesBuffer>(value)
+                .unwrap_err()
+                .to_string()
+        };
+
+        let out_of_bounds = broken(&|v| v["contour_ranges"][2] = json!({"start": 6, "end": 10}));
+        assert!(out_of_bounds.contains("contour range 2 exceeds 9 points"));
+
+        let overlapping = broken(&|v| v["contour_ranges"][1] = json!({"start": 2, "end": 6}));
+        assert!(overlapping.contains("contour range 1 overlaps or precedes the previous one"));
+
+        let unordered = broken(&|v| {
+            v["contour_ranges"][0] = json!({"start": 3, "end": 6});
+            v["contour_ranges"][1] = json!({"start": 0, "end": 3});
+        });
+        assert!(unordered.contains("contour range 1 overlaps or precedes the previous one"));
+
+        let uncovered = broken(&|v| v["shape_ranges"][1] = json!({"start": 2, "end": 2}));
+        assert!(uncovered.contains("shape ranges cover 2 of 3 contours"));
+
+        // an edited buffer with unused points is restored without compacting
+        let mut edited = buffer.clone();
+        edited.remove_shape(0);
+        edited.replace_shape(0, &int_shapes![[[[7, 7], [8, 7], [7, 8],],],][0]);
+        let text = serde_json::to_string(&edited).unwrap();
+        let restored: FlatShapesBuffer = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.points.len(), 9);
+        assert_eq!(restored.to_shapes(), edited.to_shapes());
+
+        let mut contours = FlatContoursBuffer::default();
+        contours.set_with_shapes(&shapes);
+        let text = serde_json::to_string(&contours).unwrap();
+        let restored: FlatContoursBuffer = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.to_contours(), contours.to_contours());
+
+        let mut edited = contours.clone();
+        edited.remove_contour(1);
+        edited.replace_contour(0, &int_path![[1, 1], [2, 1], [1, 2],]);
+        let text_edited = serde_json::to_string(&edited).unwrap();
+        let restored: FlatContoursBuffer = serde_json::from_str(&text_edited).unwrap();
+        assert_eq!(restored.to_contours(), edited.to_contours());
+
+        let mut value: Value = serde_json::from_str(&text).unwrap();
+        value["ranges"][0] = json!({"start": 4, "end": 3});
+        let reversed = serde_json::from_value::<FlatContoursBuffer>(value).unwrap_err();
+        assert!(
+            reversed
+                .to_string()
+                .contains("contour range 0 has start greater than end")
+        );
+
+        let float =
+            FloatFlatShapesBuffer::try_from_parts(vec![[0.0f64, 1.0]; 3], vec![0..2, 2..3], vec![0..2, 2..2])
+                .unwrap();
+        let text = serde_json::to_string(&float).unwrap();
+        let restored: FloatFlatShapesBuffer<[f64; 2]> = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored.points, float.points);
+        assert!(
+            serde_json::from_str::<FloatFlatShapesBuffer<[f64; 2]>>(&text.replace("\"end\":3", "\"end\":4"))
+                .is_err()
+        );
+    }
+}
@@ -1,3 +1,7 @@
 pub mod buffer;
+pub mod edit;
 pub mod float;
+pub mod iter;
+pub mod layout;
 pub mod source;
+pub mod view;
@@ -1,9 +1,8 @@
 use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::source::adapter::AdapterBuilder;
 use crate::source::resource::ShapeResource;
 use i_float::adapter::FloatPointAdapter;
 use i_float::float::compatible::FloatPointCompatible;
-use i_float::float::number::FloatNumber;
-use i_float::float::rect::FloatRect;
 
 impl FlatContoursBuffer {
     #[inline]
@@ -12,41 +11,8 @@ impl FlatContoursBuffer {
         P: FloatPointCompatible,
         R: ShapeResource<P> + ?Sized,
     {
-        let mut contours_count = 0;
-        let mut points_count = 0;
-        let mut min_x = P::Scalar::MAX;
-        let mut max_x = P::Scalar::MIN;
-        let mut min_y = P::Scalar::MAX;
-        let mut max_y = P::Scalar::MIN;
-        for contour in resource.iter_paths() {
-            contours_count += 1;
-            points_count += contour.len();
-            for p in contour.iter() {
-                min_x = min_x.min(p.x());
-                max_x = max_x.max(p.x());
-                min_y = min_y.min(p.y());
-                max_y = max_y.max(p.y());
-            }
-        }
-
-        self.clear_and_reserve(points_count, contours_count);
-        if points_count == 0 {
-            return FloatPointAdapter::new(FloatRect::zero());
-        }
-
-        let rect = FloatRect::new(min_x, max_x, min_y, max_y);
-        let adapter = FloatPointAdapter::new(rect);
-
-        let mut offset = 0;
-        for contour in resource.iter_paths() {
-            for p in contour.iter() {
-                self.points.push(adapter.float_to_int(p));
-            }
-            let contour_len = contour.len();
-            self.ranges.push(offset..offset + contour_len);
-            offset += contour_len;
-        }
-
+        let adapter = AdapterBuilder::new().add_resource(resource).build();
+        self.set_with_resource_and_adapter(resource, adapter.clone());
         adapter
     }
 
@@ -78,52 +44,93 @@ impl FlatContoursBuffer {
             offset += contour_len;
         }
     }
-}
 
-impl FlatShapesBuffer {
+    /// Same as `set_with_resource`, but the adapter bounds are expanded by `margin`
+    /// so points slightly outside the resource can be converted later.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FloatPointAdapter)` the adapter used for the conversion.
+    /// - `None` if `margin` is negative, so the bounds no longer cover the resource,
+    ///   or the bounds are not finite, the buffer is left unchanged.
     #[inline]
-    pub fn set_with_resource<P, R>(&mut self, resource: &R) -> FloatPointAdapter<P>
+    pub fn set_with_resource_and_margin<P, R>(
+        &mut self,
+        resource: &R,
+        margin: P::Scalar,
+    ) -> Option<FloatPointAdapter<P>>
     where
         P: FloatPointCompatible,
         R: ShapeResource<P> + ?Sized,
     {
-        let mut contours_count = 0;
-        let mut points_count = 0;
-        let mut min_x = P::Scalar::MAX;
-        let mut max_x = P::Scalar::MIN;
-        let mut min_y = P::Scalar::MAX;
-        let mut max_y = P::Scalar::MIN;
-        for contour in resource.iter_paths() {
-            contours_count += 1;
-            points_count += contour.len();
-            for p in contour.iter() {
-                min_x = min_x.min(p.x());
-                max_x = max_x.max(p.x());
-                min_y = min_y.min(p.y());
-                max_y = max_y.max(p.y());
-            }
-        }
-
-        self.clear_and_reserve(points_count, contours_count, usize::from(contours_count > 0));
-        if points_count == 0 {
-            return FloatPointAdapter::new(FloatRect::zero());
-        }
-
-        let rect = FloatRect::new(min_x, max_x, min_y, max_y);
-        let adapter = FloatPointAdapter::new(rect);
+        self.set_with_resource_and_bounds(resource, AdapterBuilder::new().margin(margin))
+    }
 
-        let mut offset = 0;
-        for contour in resource.iter_paths() {
-            for p in contour.iter() {
-                self.points.push(adapter.float_to_int(p));
-            }
-            let contour_len = contour.len();
-            self.contour_ranges.push(offset..offset + contour_len);
-            offset += contour_len;
+    /// Same as `set_with_resource`, but the adapter is built by `bounds` with the points
+    /// of `resource` added. Other resources, a margin or a fixed scale set on `bounds`
+    /// are taken into account, only the points of `resource` are added to the buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FloatPointAdapter)` the adapter used for the conversion.
+    /// - `None` if `try_build` of the resulting builder fails or a point of `resource`
+    ///   is outside the built bounds, the buffer is left unchanged.
+    #[inline]
+    pub fn set_with_resource_and_bounds<P, R>(
+        &mut self,
+        resource: &R,
+        bounds: &AdapterBuilder<P>,
+    ) -> Option<FloatPointAdapter<P>>
+    where
+        P: FloatPointCompatible,
+        R: ShapeResource<P> + ?Sized,
+    {
+        let adapter = bounds.clone().add_resource(resource).try_build()?;
+        let is_covered = resource
+            .iter_paths()
+            .all(|path| path.iter().all(|p| adapter.rect.contains(p)));
+        if !is_covered {
+            return None;
         }
+        self.set_with_resource_and_adapter(resource, adapter.clone());
+        Some(adapter)
+    }
 
-        self.shape_ranges.push(0..contours_count);
+    /// Same as `set_with_resource`, but with a fixed float to int `scale`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FloatPointAdapter)` the adapter used for the conversion.
+    /// - `None` if the scale is not positive and finite or the scaled bounds do not fit into `i32`,
+    ///   the buffer is left unchanged.
+    #[inline]
+    pub fn set_with_resource_and_scale<P, R>(
+        &mut self,
+        resource: &R,
+        scale: f64,
+    ) -> Option<FloatPointAdapter<P>>
+    where
+        P: FloatPointCompatible,
+        R: ShapeResource<P> + ?Sized,
+    {
+        let adapter = AdapterBuilder::new()
+            .add_resource(resource)
+            .scale(scale)
+            .try_build()?;
+        self.set_with_resource_and_adapter(resource, adapter.clone());
+        Some(adapter)
+    }
+}
 
+impl FlatShapesBuffer {
+    #[inline]
+    pub fn set_with_resource<P, R>(&mut self, resource: &R) -> FloatPointAdapter<P>
+    where
+        P: FloatPointCompatible,
+        R: ShapeResource<P> + ?Sized,
+    {
+        let adapter = AdapterBuilder::new().add_resource(resource).build();
+        self.set_with_resource_and_adapter(resource, adapter.clone());
         adapter
     }
 
@@ -157,6 +164,82 @@ impl FlatShapesBuffer {
 
         self.shape_ranges.push(0..contours_count);
     }
+
+    /// Same as `set_with_resource`, but the adapter bounds are expanded by `margin`
+    /// so points slightly outside the resource can be converted later.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FloatPointAdapter)` the adapter used for the conversion.
+    /// - `None` if `margin` is negative, so the bounds no longer cover the resource,
+    ///   or the bounds are not finite, the buffer is left unchanged.
+    #[inline]
+    pub fn set_with_resource_and_margin<P, R>(
+        &mut self,
+        resource: &R,
+        margin: P::Scalar,
+    ) -> Option<FloatPointAdapter<P>>
+    where
+        P: FloatPointCompatible,
+        R: ShapeResource<P> + ?Sized,
+    {
+        self.set_with_resource_and_bounds(resource, AdapterBuilder::new().margin(margin))
+    }
+
+    /// Same as `set_with_resource`, but the adapter is built by `bounds` with the points
+    /// of `resource` added. Other resources, a margin or a fixed scale set on `bounds`
+    /// are taken into account, only the points of `resource` are added to the buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FloatPointAdapter)` the adapter used for the conversion.
+    /// - `None` if `try_build` of the resulting builder fails or a point of `resource`
+    ///   is outside the built bounds, the buffer is left unchanged.
+    #[inline]
+    pub fn set_with_resource_and_bounds<P, R>(
+        &mut self,
+        resource: &R,
+        bounds: &AdapterBuilder<P>,
+    ) -> Option<FloatPointAdapter<P>>
+    where
+        P: FloatPointCompatible,
+        R: ShapeResource<P> + ?Sized,
+    {
+        let adapter = bounds.clone().add_resource(resource).try_build()?;
+        let is_covered = resource
+            .iter_paths()
+            .all(|path| path.iter().all(|p| adapter.rect.contains(p)));
+        if !is_covered {
+            return None;
+        }
+        self.set_with_resource_and_adapter(resource, adapter.clone());
+        Some(adapter)
+    }
+
+    /// Same as `set_with_resource`, but with a fixed float to int `scale`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FloatPointAdapter)` the adapter used for the conversion.
+    /// - `None` if the scale is not positive and finite or the scaled bounds do not fit into `i32`,
+    ///   the buffer is left unchanged.
+    #[inline]
+    pub fn set_with_resource_and_scale<P, R>(
+        &mut self,
+        resource: &R,
+        scale: f64,
+    ) -> Option<FloatPointAdapter<P>>
+    where
+        P: FloatPointCompatible,
+        R: ShapeResource<P> + ?Sized,
+    {
+        let adapter = AdapterBuilder::new()
+            .add_resource(resource)
+            .scale(scale)
+            .try_build()?;
+        self.set_with_resource_and_adapter(resource, adapter.clone());
+        Some(adapter)
+    }
 }
 
 #[cfg(test)]
@@ -164,6 +247,7 @@ mod tests {
     use super::*;
     use alloc::vec;
     use alloc::vec::Vec;
+    use i_float::float::rect::FloatRect;
 
     #[test]
     fn test_shapes_buffer_set_with_resource() {
@@ -200,4 +284,81 @@ mod tests {
         assert_eq!(buffer.contour_ranges, vec![0..3]);
         assert_eq!(buffer.to_shapes().len(), 1);
     }
+
+    #[test]
+    fn test_set_with_resource_and_margin() {
+        let contour: Vec<[f64; 2]> = vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0]];
+
+        let mut contours = FlatContoursBuffer::default();
+        let adapter = contours.set_with_resource_and_margin(&contour, 1.0).unwrap();
+        assert_eq!(adapter.rect.min_x, -1.0);
+        assert_eq!(adapter.rect.max_y, 5.0);
+        assert_eq!(contours.ranges, vec![0..3]);
+        assert_eq!(
+            adapter.int_to_float(&adapter.float_to_int(&[5.0, 5.0])),
+            [5.0, 5.0]
+        );
+
+        let mut shapes = FlatShapesBuffer::default();
+        let adapter = shapes.set_with_resource_and_margin(&contour, 1.0).unwrap();
+        assert_eq!(adapter.rect.min_y, -1.0);
+        assert_eq!(shapes.contour_ranges, vec![0..3]);
+        assert_eq!(shapes.points, contours.points);
+
+        // a negative margin cuts off the resource, a large one inverts the bounds
+        let points = shapes.points.clone();
+        assert!(shapes.set_with_resource_and_margin(&contour, -1.0).is_none());
+        assert!(shapes.set_with_resource_and_margin(&contour, -3.0).is_none());
+        assert!(contours.set_with_resource_and_margin(&contour, -3.0).is_none());
+        assert_eq!(shapes.points, points);
+        assert_eq!(contours.ranges, vec![0..3]);
+    }
+
+    #[test]
+    fn test_set_with_resource_and_bounds() {
+        let contour: Vec<[f64; 2]> = vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0]];
+        let offset: Vec<[f64; 2]> = vec![[-1.0, -1.0], [5.0, -1.0], [5.0, 5.0]];
+        let far: Vec<Vec<[f64; 2]>> = vec![vec![[0.0, 10.0], [1.0, 10.0], [1.0, 11.0]]];
+
+        let mut bounds = AdapterBuilder::new();
+        bounds.add_resource(&offset).add_resource(&far);
+
+        let mut contours = FlatContoursBuffer::default();
+        let adapter = contours.set_with_resource_and_bounds(&contour, &bounds).unwrap();
+        assert_eq!(adapter.rect.min_x, -1.0);
+        assert_eq!(adapter.rect.max_y, 11.0);
+        assert_eq!(contours.ranges, vec![0..3]);
+
+        let mut shapes = FlatShapesBuffer::default();
+        let adapter = shapes.set_with_resource_and_bounds(&contour, &bounds).unwrap();
+        assert_eq!(adapter.rect.max_x, 5.0);
+        assert_eq!(shapes.contour_ranges, vec![0..3]);
+        assert_eq!(shapes.shape_ranges, vec![0..1]);
+
+        // the far points do not fit into i32 with this scale
+        bounds.scale(1e9);
+        assert!(contours.set_with_resource_and_bounds(&contour, &bounds).is_none());
+        assert!(shapes.set_with_resource_and_bounds(&contour, &bounds).is_none());
+        assert_eq!(contours.ranges, vec![0..3]);
+        assert_eq!(shapes.contour_ranges, vec![0..3]);
+    }
+
+    #[test]
+    fn test_set_with_resource_and_scale() {
+        let contour: Vec<[f64; 2]> = vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0]];
+
+        let mut contours = FlatContoursBuffer::default();
+        let adapter = contours.set_with_resource_and_scale(&contour, 2.0).unwrap();
+        assert_eq!(adapter.dir_scale, 2.0);
+        assert_eq!(contours.points[1].x - contours.points[0].x, 8);
+        assert!(contours.set_with_resource_and_scale(&contour, 1e10).is_none());
+        assert_eq!(contours.ranges, vec![0..3]);
+
+        let mut shapes = FlatShapesBuffer::default();
+        let adapter = shapes.set_with_resource_and_scale(&contour, 2.0).unwrap();
+        assert_eq!(adapter.dir_scale, 2.0);
+        assert_eq!(shapes.points, contours.points);
+        assert!(shapes.set_with_resource_and_scale(&contour, -2.0).is_none());
+        assert_eq!(shapes.contour_ranges, vec![0..3]);
+    }
 }
@@ -0,0 +1,248 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use alloc::vec::Vec;
+use core::ops::Range;
+use i_float::int::point::IntPoint;
+
+/// A borrowed shape inside a flat buffer.
+///
+/// The first contour is the outer boundary and the rest are holes.
+#[derive(Debug, Clone, Copy)]
+pub struct ShapeView<'a, P = IntPoint> {
+    points: &'a [P],
+    ranges: &'a [Range<usize>],
+}
+
+impl<'a, P> ShapeView<'a, P> {
+    #[inline]
+    pub fn new(points: &'a [P], ranges: &'a [Range<usize>]) -> Self {
+        Self { points, ranges }
+    }
+
+    /// The number of contours, the outer one included.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The outer contour, empty if the shape has no contours.
+    #[inline]
+    pub fn outer(&self) -> &'a [P] {
+        self.contour(0).unwrap_or(&[])
+    }
+
+    #[inline]
+    pub fn holes(&self) -> impl ExactSizeIterator<Item = &'a [P]> + 'a {
+        let points = self.points;
+        self.ranges
+            .iter()
+            .skip(1)
+            .map(move |range| &points[range.clone()])
+    }
+
+    #[inline]
+    pub fn contour(&self, index: usize) -> Option<&'a [P]> {
+        let range = self.ranges.get(index)?;
+        Some(&self.points[range.clone()])
+    }
+
+    #[inline]
+    pub fn contours(&self) -> impl ExactSizeIterator<Item = &'a [P]> + 'a {
+        let points = self.points;
+        self.ranges.iter().map(move |range| &points[range.clone()])
+    }
+
+    #[inline]
+    pub fn to_shape(&self) -> Vec<Vec<P>>
+    where
+        P: Clone,
+    {
+        self.contours().map(<[P]>::to_vec).collect()
+    }
+}
+
+impl FlatContoursBuffer {
+    /// Returns the contour at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn contour(&self, index: usize) -> &[IntPoint] {
+        &self.points[self.ranges[index].clone()]
+    }
+
+    /// Iterates over all contours without allocation.
+    #[inline]
+    pub fn contours(&self) -> impl ExactSizeIterator<Item = &[IntPoint]> + '_ {
+        self.ranges.iter().map(|range| &self.points[range.clone()])
+    }
+}
+
+impl FlatShapesBuffer {
+    /// Returns a borrowed view of the shape at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn shape(&self, index: usize) -> ShapeView<'_> {
+        ShapeView::new(
+            &self.points,
+            &self.contour_ranges[self.shape_ranges[index].clone()],
+        )
+    }
+
+    /// Iterates over all shapes without allocation.
+    #[inline]
+    pub fn shapes(&self) -> impl ExactSizeIterator<Item = ShapeView<'_>> + '_ {
+        self.shape_ranges
+            .iter()
+            .map(|range| ShapeView::new(&self.points, &self.contour_ranges[range.clone()]))
+    }
+
+    /// Returns the contour at `index`, counted over all shapes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn contour(&self, index: usize) -> &[IntPoint] {
+        &self.points[self.contour_ranges[index].clone()]
+    }
+
+    /// Iterates over the contours of all shapes without allocation.
+    #[inline]
+    pub fn contours(&self) -> impl ExactSizeIterator<Item = &[IntPoint]> + '_ {
+        self.contour_ranges
+            .iter()
+            .map(|range| &self.points[range.clone()])
+    }
+}
+
+impl<P> FloatFlatContoursBuffer<P> {
+    /// Returns the contour at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn contour(&self, index: usize) -> &[P] {
+        &self.points[self.ranges[index].clone()]
+    }
+
+    /// Iterates over all contours without allocation.
+    #[inline]
+    pub fn contours(&self) -> impl ExactSizeIterator<Item = &[P]> + '_ {
+        self.ranges.iter().map(|range| &self.points[range.clone()])
+    }
+}
+
+impl<P> FloatFlatShapesBuffer<P> {
+    /// Returns a borrowed view of the shape at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn shape(&self, index: usize) -> ShapeView<'_, P> {
+        ShapeView::new(
+            &self.points,
+            &self.contour_ranges[self.shape_ranges[index].clone()],
+        )
+    }
+
+    /// Iterates over all shapes without allocation.
+    #[inline]
+    pub fn shapes(&self) -> impl ExactSizeIterator<Item = ShapeView<'_, P>> + '_ {
+        self.shape_ranges
+            .iter()
+            .map(|range| ShapeView::new(&self.points, &self.contour_ranges[range.clone()]))
+    }
+
+    /// Returns the contour at `index`, counted over all shapes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn contour(&self, index: usize) -> &[P] {
+        &self.points[self.contour_ranges[index].clone()]
+    }
+
+    /// Iterates over the contours of all shapes without allocation.
+    #[inline]
+    pub fn contours(&self) -> impl ExactSizeIterator<Item = &[P]> + '_ {
+        self.contour_ranges
+            .iter()
+            .map(|range| &self.points[range.clone()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::flat::float::FloatFlatShapesBuffer;
+    use crate::float::area::Area as FloatArea;
+    use crate::int::area::{Area, ExactArea};
+    use crate::int::location::{ClassifyPoint, PointLocation};
+    use crate::{int_shapes, paths};
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+
+    #[test]
+    fn test_0() {
+        let shapes = int_shapes![
+            [
+                [[0, 0], [0, 10], [10, 10], [10, 0],],
+                [[2, 2], [8, 2], [8, 8], [2, 8],],
+            ],
+            [[[20, 0], [20, 5], [25, 5], [25, 0],],],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let views: Vec<_> = buffer.shapes().collect();
+        assert_eq!(views.len(), 2);
+        assert_eq!(views[0].outer(), shapes[0][0].as_slice());
+        assert_eq!(views[0].holes().next().unwrap(), shapes[0][1].as_slice());
+        assert_eq!(views[1].holes().len(), 0);
+        assert_eq!(views[1].to_shape(), shapes[1]);
+
+        let shape = buffer.shape(0);
+        assert_eq!(shape.area_two(), shapes[0].area_two());
+        assert_eq!(shape.exact_area_two(), shapes[0].exact_area_two());
+        assert_eq!(shape.classify_point(IntPoint::new(1, 1)), PointLocation::Inside);
+        assert_eq!(shape.classify_point(IntPoint::new(5, 5)), PointLocation::Outside);
+
+        assert_eq!(buffer.contours().len(), 3);
+        assert_eq!(buffer.contour(2), shapes[1][0].as_slice());
+
+        let mut contours = FlatContoursBuffer::default();
+        contours.set_with_shapes(&shapes);
+        assert!(contours.contours().eq(buffer.contours()));
+    }
+
+    #[test]
+    fn test_1() {
+        let shape = paths![
+            [[0.0f64, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[1.0, 1.0], [1.0, 3.0], [3.0, 3.0], [3.0, 1.0]],
+        ];
+
+        let mut buffer = FloatFlatShapesBuffer::default();
+        buffer.set_with_resource(&shape);
+
+        let view = buffer.shape(0);
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.area(), shape.area());
+        assert_eq!(view.area(), 12.0);
+        assert_eq!(buffer.contour(1), shape[1].as_slice());
+    }
+}
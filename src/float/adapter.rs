@@ -3,6 +3,8 @@ use crate::flat::buffer::FlatContoursBuffer;
 use crate::flat::float::FloatFlatContoursBuffer;
 use crate::int::path::IntPath;
 use crate::int::shape::{IntContour, IntShape, IntShapes};
+use alloc::vec::Vec;
+use core::ops::Range;
 use i_float::adapter::FloatPointAdapter;
 use i_float::float::compatible::FloatPointCompatible;
 use i_float::int::point::IntPoint;
@@ -63,10 +65,8 @@ impl<P: FloatPointCompatible> ShapesToFloat<P> for [IntShape] {
 impl<P: FloatPointCompatible> BufferToFloat<P> for FlatContoursBuffer {
     #[inline(always)]
     fn to_float(&self, adapter: &FloatPointAdapter<P>) -> FloatFlatContoursBuffer<P> {
-        FloatFlatContoursBuffer {
-            points: self.points.to_float(adapter),
-            ranges: self.ranges.clone(),
-        }
+        let (points, ranges) = convert_contours(&self.points, &self.ranges, |p| adapter.int_to_float(p));
+        FloatFlatContoursBuffer { points, ranges }
     }
 }
 
@@ -94,9 +94,24 @@ impl<P: FloatPointCompatible> ShapesToInt<P> for [Shape<P>] {
 impl<P: FloatPointCompatible> BufferToInt<P> for FloatFlatContoursBuffer<P> {
     #[inline(always)]
     fn to_int(&self, adapter: &FloatPointAdapter<P>) -> FlatContoursBuffer {
-        FlatContoursBuffer {
-            points: self.points.to_int(adapter),
-            ranges: self.ranges.clone(),
-        }
+        let (points, ranges) = convert_contours(&self.points, &self.ranges, |p| adapter.float_to_int(p));
+        FlatContoursBuffer { points, ranges }
     }
 }
+
+/// Converts only the points covered by `ranges`, the result has no unused points.
+#[inline]
+fn convert_contours<A, B, F>(points: &[A], ranges: &[Range<usize>], convert: F) -> (Vec<B>, Vec<Range<usize>>)
+where
+    F: Fn(&A) -> B,
+{
+    let count = ranges.iter().map(|range| range.len()).sum();
+    let mut result = Vec::with_capacity(count);
+    let mut result_ranges = Vec::with_capacity(ranges.len());
+    for range in ranges.iter() {
+        let start = result.len();
+        result.extend(points[range.clone()].iter().map(&convert));
+        result_ranges.push(start..result.len());
+    }
+    (result, result_ranges)
+}
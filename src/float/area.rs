@@ -1,4 +1,5 @@
 use crate::base::data::{Contour, Shape};
+use crate::flat::view::ShapeView;
 use i_float::float::compatible::FloatPointCompatible;
 use i_float::float::number::FloatNumber;
 
@@ -48,3 +49,14 @@ impl<P: FloatPointCompatible> Area<P> for [Shape<P>] {
         area
     }
 }
+
+impl<P: FloatPointCompatible> Area<P> for ShapeView<'_, P> {
+    #[inline]
+    fn area(&self) -> P::Scalar {
+        let mut area = P::Scalar::from_float(0.0);
+        for contour in self.contours() {
+            area = area + contour.area();
+        }
+        area
+    }
+}
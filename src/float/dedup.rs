@@ -0,0 +1,178 @@
+use crate::base::data::{Contour, Shape, Shapes};
+use crate::flat::edit::{rewrite_contours, rewrite_shapes};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use crate::int::dedup::{DedupChange, DedupKind, Near, dedup_edit, dedup_shape, dedup_slice};
+use alloc::vec::Vec;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+
+/// Trait for merging near-duplicate points of float structures.
+pub trait DedupPoints<P: FloatPointCompatible> {
+    /// Merges consecutive points closer than or equal to `max_distance` to the last kept point,
+    /// a closing point near the first one is removed too.
+    /// Contours with fewer than 3 points left are removed.
+    /// A negative or not finite `max_distance` merges nothing.
+    ///
+    /// # Returns
+    ///
+    /// - A list of the modified contours, empty if nothing was changed.
+    fn dedup_points(&mut self, max_distance: P::Scalar) -> Vec<DedupChange>;
+}
+
+impl<P: FloatPointCompatible> DedupPoints<P> for Contour<P> {
+    fn dedup_points(&mut self, max_distance: P::Scalar) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let n = self.len();
+        let len = dedup_slice(self, FloatNear::new(max_distance));
+        if let Some(kind) = DedupKind::new(n, len) {
+            changes.push(DedupChange {
+                shape: 0,
+                contour: 0,
+                kind,
+            });
+            self.truncate(if len < 3 { 0 } else { len });
+        }
+        changes
+    }
+}
+
+impl<P: FloatPointCompatible> DedupPoints<P> for Shape<P> {
+    fn dedup_points(&mut self, max_distance: P::Scalar) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        dedup_shape(self, 0, FloatNear::new(max_distance), &mut changes);
+        changes
+    }
+}
+
+impl<P: FloatPointCompatible> DedupPoints<P> for Shapes<P> {
+    fn dedup_points(&mut self, max_distance: P::Scalar) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let near = FloatNear::new(max_distance);
+        let mut any_empty = false;
+        for (index, shape) in self.iter_mut().enumerate() {
+            dedup_shape(shape, index, near, &mut changes);
+            any_empty |= shape.is_empty();
+        }
+
+        if any_empty {
+            self.retain(|shape| !shape.is_empty());
+        }
+
+        changes
+    }
+}
+
+impl<P: FloatPointCompatible> DedupPoints<P> for FloatFlatContoursBuffer<P> {
+    fn dedup_points(&mut self, max_distance: P::Scalar) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let near = FloatNear::new(max_distance);
+        rewrite_contours(&mut self.points, &mut self.ranges, |contour, points| {
+            dedup_edit(points, 0, contour, near, &mut changes)
+        });
+        changes
+    }
+}
+
+impl<P: FloatPointCompatible> DedupPoints<P> for FloatFlatShapesBuffer<P> {
+    fn dedup_points(&mut self, max_distance: P::Scalar) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let near = FloatNear::new(max_distance);
+        rewrite_shapes(
+            &mut self.points,
+            &mut self.contour_ranges,
+            &mut self.shape_ranges,
+            |shape, contour, points| dedup_edit(points, shape, contour, near, &mut changes),
+        );
+        changes
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FloatNear<T> {
+    sqr_distance: T,
+}
+
+impl<T: FloatNumber> FloatNear<T> {
+    #[inline]
+    fn new(max_distance: T) -> Self {
+        // a negative squared distance is never reached
+        let sqr_distance = if max_distance >= T::from_float(0.0) && max_distance.to_f64().is_finite() {
+            max_distance * max_distance
+        } else {
+            T::from_float(-1.0)
+        };
+        Self { sqr_distance }
+    }
+}
+
+impl<P: FloatPointCompatible> Near<P> for FloatNear<P::Scalar> {
+    #[inline]
+    fn is_near(&self, a: &P, b: &P) -> bool {
+        let dx = b.x() - a.x();
+        let dy = b.y() - a.y();
+        dx * dx + dy * dy <= self.sqr_distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::float::FloatFlatShapesBuffer;
+    use crate::float::dedup::DedupPoints;
+    use crate::int::dedup::{DedupChange, DedupKind};
+    use crate::{path, paths};
+
+    #[test]
+    fn test_0() {
+        let mut contour = path![[0.0f64, 0.0], [0.05, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.02]];
+        let changes = contour.dedup_points(0.1);
+        assert_eq!(contour, path![[0.0f64, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+        assert_eq!(
+            changes,
+            [DedupChange {
+                shape: 0,
+                contour: 0,
+                kind: DedupKind::Merged { removed_points: 2 }
+            }]
+        );
+
+        let shape = paths![
+            [[0.0f64, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[1.0, 1.0], [1.01, 1.0], [1.0, 1.01]],
+            [[2.0, 2.0], [3.0, 2.0], [3.0, 2.01], [3.0, 3.0]],
+        ];
+
+        let mut buffer = FloatFlatShapesBuffer::default();
+        buffer.set_with_resource(&shape);
+        let changes = buffer.dedup_points(0.1);
+
+        assert_eq!(
+            changes,
+            [
+                DedupChange {
+                    shape: 0,
+                    contour: 1,
+                    kind: DedupKind::Removed
+                },
+                DedupChange {
+                    shape: 0,
+                    contour: 2,
+                    kind: DedupKind::Merged { removed_points: 1 }
+                },
+            ]
+        );
+
+        let mut expected = shape.clone();
+        assert_eq!(expected.dedup_points(0.1), changes);
+        assert_eq!(buffer.to_shapes(), [expected]);
+    }
+
+    #[test]
+    fn test_1() {
+        let contour = path![[0.0f32, 0.0], [0.05, 0.0], [1.0, 0.0], [1.0, 1.0]];
+        for max_distance in [-0.1, f32::NAN, f32::INFINITY] {
+            let mut modified = contour.clone();
+            assert!(modified.dedup_points(max_distance).is_empty());
+            assert_eq!(modified, contour);
+        }
+    }
+}
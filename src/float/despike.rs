@@ -1,12 +1,12 @@
 use crate::base::data::{Contour, Shape, Shapes};
-use crate::flat::buffer::FlatContoursBuffer;
-use crate::flat::float::FloatFlatContoursBuffer;
-use crate::float::adapter::{
-    BufferToFloat, BufferToInt, PathToFloat, PathToInt, ShapeToFloat, ShapeToInt, ShapesToFloat, ShapesToInt,
-};
-use crate::int::despike::DeSpike;
+use crate::flat::edit::{rewrite_contours, rewrite_shapes};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use crate::float::adapter::{PathToFloat, PathToInt, ShapeToFloat, ShapeToInt, ShapesToFloat, ShapesToInt};
+use crate::int::despike::{DeSpike, SpikeThreshold, despike_edit};
+use alloc::vec::Vec;
 use i_float::adapter::FloatPointAdapter;
 use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
 
 /// A trait that provides methods for despike complex geometrical structures.
 pub trait DeSpikeContour<P: FloatPointCompatible> {
@@ -17,6 +17,14 @@ pub trait DeSpikeContour<P: FloatPointCompatible> {
     /// - `true` if the structure was simplified successfully.
     /// - `false` if the structure was already simple and no modification was made.
     fn despike_contour(&mut self, adapter: &FloatPointAdapter<P>) -> bool;
+
+    /// Removes spikes and near-spikes in-place, the `Width` threshold is given in float units.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the structure was simplified successfully.
+    /// - `false` if the structure was already simple and no modification was made.
+    fn despike_contour_with(&mut self, threshold: SpikeThreshold, adapter: &FloatPointAdapter<P>) -> bool;
 }
 
 impl<P: FloatPointCompatible> DeSpikeContour<P> for Contour<P> {
@@ -33,6 +41,21 @@ impl<P: FloatPointCompatible> DeSpikeContour<P> for Contour<P> {
         }
         true
     }
+
+    fn despike_contour_with(&mut self, threshold: SpikeThreshold, adapter: &FloatPointAdapter<P>) -> bool {
+        let threshold = int_threshold(threshold, adapter);
+        let mut int_contour = self.to_int(adapter);
+        if !int_contour.remove_spikes_with(threshold) {
+            return false;
+        }
+
+        if int_contour.is_empty() {
+            self.clear();
+        } else {
+            *self = int_contour.to_float(adapter);
+        }
+        true
+    }
 }
 
 impl<P: FloatPointCompatible> DeSpikeContour<P> for Shape<P> {
@@ -49,6 +72,21 @@ impl<P: FloatPointCompatible> DeSpikeContour<P> for Shape<P> {
         }
         true
     }
+
+    fn despike_contour_with(&mut self, threshold: SpikeThreshold, adapter: &FloatPointAdapter<P>) -> bool {
+        let threshold = int_threshold(threshold, adapter);
+        let mut int_shape = self.to_int(adapter);
+        if !int_shape.remove_spikes_with(threshold) {
+            return false;
+        }
+
+        if int_shape.is_empty() {
+            self.clear();
+        } else {
+            *self = int_shape.to_float(adapter);
+        }
+        true
+    }
 }
 
 impl<P: FloatPointCompatible> DeSpikeContour<P> for Shapes<P> {
@@ -65,26 +103,168 @@ impl<P: FloatPointCompatible> DeSpikeContour<P> for Shapes<P> {
         }
         true
     }
+
+    fn despike_contour_with(&mut self, threshold: SpikeThreshold, adapter: &FloatPointAdapter<P>) -> bool {
+        let threshold = int_threshold(threshold, adapter);
+        let mut int_shapes = self.to_int(adapter);
+        if !int_shapes.remove_spikes_with(threshold) {
+            return false;
+        }
+
+        if int_shapes.is_empty() {
+            self.clear();
+        } else {
+            *self = int_shapes.to_float(adapter);
+        }
+        true
+    }
 }
 
 impl<P: FloatPointCompatible> DeSpikeContour<P> for FloatFlatContoursBuffer<P> {
+    /// Removes spikes from every contour in place, degenerate contours are removed.
+    #[inline]
     fn despike_contour(&mut self, adapter: &FloatPointAdapter<P>) -> bool {
-        let int_buffer = self.to_int(adapter);
-        let mut out = FlatContoursBuffer::with_capacity(int_buffer.points.len());
-        let mut changed = false;
-
-        for mut contour in int_buffer.to_contours().into_iter() {
-            changed |= contour.remove_spikes();
-            if !contour.is_empty() {
-                out.add_contour(&contour);
-            }
-        }
+        despike_flat_contours(self, None, adapter)
+    }
 
-        if !changed {
-            return false;
-        }
+    #[inline]
+    fn despike_contour_with(&mut self, threshold: SpikeThreshold, adapter: &FloatPointAdapter<P>) -> bool {
+        let threshold = int_threshold(threshold, adapter);
+        despike_flat_contours(self, Some(threshold), adapter)
+    }
+}
 
-        *self = out.to_float(adapter);
-        true
+impl<P: FloatPointCompatible> DeSpikeContour<P> for FloatFlatShapesBuffer<P> {
+    /// Removes spikes from every contour in place, degenerate holes are removed
+    /// and a shape is removed if its outer contour is degenerate.
+    #[inline]
+    fn despike_contour(&mut self, adapter: &FloatPointAdapter<P>) -> bool {
+        rewrite_shapes(
+            &mut self.points,
+            &mut self.contour_ranges,
+            &mut self.shape_ranges,
+            |_, _, contour| {
+                let int_contour = contour.to_int(adapter);
+                despike_edit(&int_contour, None).into_float(&int_contour, adapter)
+            },
+        )
+    }
+
+    #[inline]
+    fn despike_contour_with(&mut self, threshold: SpikeThreshold, adapter: &FloatPointAdapter<P>) -> bool {
+        let threshold = int_threshold(threshold, adapter);
+        rewrite_shapes(
+            &mut self.points,
+            &mut self.contour_ranges,
+            &mut self.shape_ranges,
+            |_, _, contour| {
+                let int_contour = contour.to_int(adapter);
+                despike_edit(&int_contour, Some(threshold)).into_float(&int_contour, adapter)
+            },
+        )
+    }
+}
+
+fn despike_flat_contours<P: FloatPointCompatible>(
+    buffer: &mut FloatFlatContoursBuffer<P>,
+    threshold: Option<SpikeThreshold>,
+    adapter: &FloatPointAdapter<P>,
+) -> bool {
+    let mut int_contour = Vec::new();
+    rewrite_contours(&mut buffer.points, &mut buffer.ranges, |_, contour| {
+        int_contour.clear();
+        int_contour.extend(contour.iter().map(|p| adapter.float_to_int(p)));
+        despike_edit(&int_contour, threshold).into_float(&int_contour, adapter)
+    })
+}
+
+#[inline]
+fn int_threshold<P: FloatPointCompatible>(
+    threshold: SpikeThreshold,
+    adapter: &FloatPointAdapter<P>,
+) -> SpikeThreshold {
+    match threshold {
+        SpikeThreshold::Angle { .. } => threshold,
+        SpikeThreshold::Width { min_width } => SpikeThreshold::Width {
+            min_width: min_width * adapter.dir_scale.to_f64(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+    use crate::float::despike::DeSpikeContour;
+    use crate::int::despike::SpikeThreshold;
+    use crate::paths;
+    use i_float::adapter::FloatPointAdapter;
+
+    #[test]
+    fn test_0() {
+        let mut shape = paths![
+            [
+                [0.0f64, 0.0],
+                [0.0, 2.0],
+                [1.0, 2.0],
+                [3.0, 2.0],
+                [4.0, 2.0],
+                [2.0, 2.0],
+                [2.0, 0.0]
+            ],
+            [[0.5, 0.5], [1.5, 0.5], [1.0, 0.5]],
+        ];
+        let adapter = FloatPointAdapter::with_iter(shape.iter().flatten());
+
+        let mut buffer = FloatFlatShapesBuffer::default();
+        buffer.set_with_resource(&shape);
+        assert!(buffer.despike_contour(&adapter));
+        assert!(shape.despike_contour(&adapter));
+        assert_eq!(buffer.to_shapes(), [shape]);
+        assert_eq!(buffer.contour_ranges.len(), 1);
+    }
+
+    #[test]
+    fn test_1() {
+        let mut contours = paths![
+            [
+                [0.0f64, 0.0],
+                [0.0, 2.0],
+                [1.0, 2.0],
+                [3.0, 2.0],
+                [4.0, 2.0],
+                [2.0, 2.0],
+                [2.0, 0.0]
+            ],
+            [[10.0, 0.0], [10.0, 2.0], [10.0, 1.0]],
+            [[20.0, 0.0], [20.0, 2.0], [22.0, 2.0], [22.0, 0.0]],
+        ];
+        let adapter = FloatPointAdapter::with_iter(contours.iter().flatten());
+
+        let mut buffer = FloatFlatContoursBuffer::default();
+        buffer.set_with_resource(&contours);
+
+        assert!(buffer.despike_contour(&adapter));
+        assert!(contours.despike_contour(&adapter));
+        assert_eq!(buffer.to_contours(), contours);
+        assert_eq!(buffer.ranges.len(), 2);
+        assert!(!buffer.despike_contour(&adapter));
+
+        // the threshold is given in float units
+        let mut near = paths![[
+            [0.0f64, 0.0],
+            [5.0, 0.0],
+            [10.0, 0.5],
+            [5.0, 0.1],
+            [5.0, 10.0],
+            [0.0, 10.0]
+        ]];
+        let adapter = FloatPointAdapter::with_iter(near.iter().flatten());
+        buffer.set_with_resource(&near);
+        let threshold = SpikeThreshold::Width { min_width: 0.5 };
+
+        assert!(buffer.despike_contour_with(threshold, &adapter));
+        assert!(near.despike_contour_with(threshold, &adapter));
+        assert_eq!(buffer.to_contours(), near);
+        assert_eq!(buffer.points.len(), 5);
     }
 }
@@ -0,0 +1,110 @@
+use crate::base::data::{Contour, Shape};
+use crate::base::direction::ContourDirection;
+use crate::flat::float::FloatFlatContoursBuffer;
+use crate::float::adapter::PathToFloat;
+use crate::int::hull::hull;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+
+pub trait ConvexHull<P: FloatPointCompatible> {
+    /// Builds the convex hull of all points, the points are snapped to the integer grid of `adapter`.
+    ///
+    /// - `direction`: The direction of the resulting contour.
+    /// - `keep_collinear`: If `true`, points lying on the hull edges are kept.
+    ///
+    /// # Returns
+    ///
+    /// - The hull contour, it has fewer than 3 points if all points are collinear.
+    fn convex_hull(
+        &self,
+        direction: ContourDirection,
+        keep_collinear: bool,
+        adapter: &FloatPointAdapter<P>,
+    ) -> Contour<P>;
+}
+
+impl<P: FloatPointCompatible> ConvexHull<P> for [P] {
+    #[inline]
+    fn convex_hull(
+        &self,
+        direction: ContourDirection,
+        keep_collinear: bool,
+        adapter: &FloatPointAdapter<P>,
+    ) -> Contour<P> {
+        let points = self.iter().map(|p| adapter.float_to_int(p)).collect();
+        hull(points, direction, keep_collinear).to_float(adapter)
+    }
+}
+
+impl<P: FloatPointCompatible> ConvexHull<P> for [Contour<P>] {
+    #[inline]
+    fn convex_hull(
+        &self,
+        direction: ContourDirection,
+        keep_collinear: bool,
+        adapter: &FloatPointAdapter<P>,
+    ) -> Contour<P> {
+        let points = self.iter().flatten().map(|p| adapter.float_to_int(p)).collect();
+        hull(points, direction, keep_collinear).to_float(adapter)
+    }
+}
+
+impl<P: FloatPointCompatible> ConvexHull<P> for [Shape<P>] {
+    #[inline]
+    fn convex_hull(
+        &self,
+        direction: ContourDirection,
+        keep_collinear: bool,
+        adapter: &FloatPointAdapter<P>,
+    ) -> Contour<P> {
+        let points = self
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|p| adapter.float_to_int(p))
+            .collect();
+        hull(points, direction, keep_collinear).to_float(adapter)
+    }
+}
+
+impl<P: FloatPointCompatible> ConvexHull<P> for FloatFlatContoursBuffer<P> {
+    #[inline]
+    fn convex_hull(
+        &self,
+        direction: ContourDirection,
+        keep_collinear: bool,
+        adapter: &FloatPointAdapter<P>,
+    ) -> Contour<P> {
+        let points = self
+            .ranges
+            .iter()
+            .flat_map(|range| self.points[range.clone()].iter())
+            .map(|p| adapter.float_to_int(p))
+            .collect();
+        hull(points, direction, keep_collinear).to_float(adapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::direction::ContourDirection;
+    use crate::float::hull::ConvexHull;
+    use crate::path;
+    use i_float::adapter::FloatPointAdapter;
+
+    #[test]
+    fn test_0() {
+        let points = path![
+            [0.0f64, 0.0],
+            [1.0, 0.5],
+            [2.0, 0.0],
+            [2.0, 2.0],
+            [1.0, 1.0],
+            [0.0, 2.0]
+        ];
+        let adapter = FloatPointAdapter::with_iter(points.iter());
+
+        let hull = points.convex_hull(ContourDirection::CounterClockwise, false, &adapter);
+        assert_eq!(hull, path![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    }
+}
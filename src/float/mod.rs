@@ -1,9 +1,15 @@
 pub mod adapter;
 pub mod area;
 pub mod count;
+pub mod dedup;
 pub mod despike;
+pub mod hull;
 pub mod init;
 pub mod int_area;
+pub mod moments;
+pub mod precision;
 pub mod rect;
 pub mod reverse;
 pub mod simple;
+pub mod snap;
+pub mod transform;
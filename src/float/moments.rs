@@ -0,0 +1,351 @@
+use crate::base::data::{Contour, Shape};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+
+/// Area properties of a region.
+#[derive(Debug, Clone, Copy)]
+pub struct FloatMoments<P: FloatPointCompatible> {
+    /// The area, holes are subtracted.
+    pub area: P::Scalar,
+    pub centroid: P,
+    /// The second moment `∫(y - cy)² dA` about the horizontal axis through the centroid.
+    pub ixx: P::Scalar,
+    /// The second moment `∫(x - cx)² dA` about the vertical axis through the centroid.
+    pub iyy: P::Scalar,
+    /// The product moment `∫(x - cx)(y - cy) dA` about the centroid.
+    pub ixy: P::Scalar,
+}
+
+/// A trait for area properties of contours and shapes.
+///
+/// The region does not depend on the contour direction:
+/// an outer contour always adds its area and holes always subtract theirs.
+pub trait ShapeMoments<P: FloatPointCompatible> {
+    /// Returns the area properties, `None` if the area is zero.
+    fn moments(&self) -> Option<FloatMoments<P>>;
+
+    /// The total length of all contours, holes included.
+    fn perimeter(&self) -> P::Scalar;
+
+    #[inline]
+    fn centroid(&self) -> Option<P> {
+        self.moments().map(|moments| moments.centroid)
+    }
+}
+
+impl<P: FloatPointCompatible> ShapeMoments<P> for [P] {
+    #[inline]
+    fn moments(&self) -> Option<FloatMoments<P>> {
+        let origin = *self.first()?;
+        let mut sum = MomentSum::new();
+        sum.add_contour(self, origin, true);
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> P::Scalar {
+        contour_perimeter(self)
+    }
+}
+
+impl<P: FloatPointCompatible> ShapeMoments<P> for [Contour<P>] {
+    #[inline]
+    fn moments(&self) -> Option<FloatMoments<P>> {
+        let origin = *self.first()?.first()?;
+        let mut sum = MomentSum::new();
+        sum.add_shape(self.iter().map(|contour| contour.as_slice()), origin);
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> P::Scalar {
+        let mut length = P::Scalar::from_float(0.0);
+        for contour in self.iter() {
+            length = length + contour_perimeter(contour);
+        }
+        length
+    }
+}
+
+impl<P: FloatPointCompatible> ShapeMoments<P> for [Shape<P>] {
+    fn moments(&self) -> Option<FloatMoments<P>> {
+        let origin = *self.iter().flatten().flatten().next()?;
+        let mut sum = MomentSum::new();
+        for shape in self.iter() {
+            sum.add_shape(shape.iter().map(|contour| contour.as_slice()), origin);
+        }
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> P::Scalar {
+        let mut length = P::Scalar::from_float(0.0);
+        for shape in self.iter() {
+            length = length + shape.perimeter();
+        }
+        length
+    }
+}
+
+/// The contours are independent, each one adds its area with the sign of its direction,
+/// so a contour in the opposite direction subtracts its area like a hole.
+/// The regions are the contours whose direction gives a positive total area.
+impl<P: FloatPointCompatible> ShapeMoments<P> for FloatFlatContoursBuffer<P> {
+    fn moments(&self) -> Option<FloatMoments<P>> {
+        let range = self.ranges.iter().find(|range| !range.is_empty())?;
+        let origin = self.points[range.start];
+        let one = P::Scalar::from_float(1.0);
+        let mut sum = MomentSum::new();
+        for range in self.ranges.iter() {
+            sum.add_signed(&MomentSum::with_contour(&self.points[range.clone()], origin), one);
+        }
+
+        let sign = if sum.s < P::Scalar::from_float(0.0) {
+            P::Scalar::from_float(-1.0)
+        } else {
+            one
+        };
+        let mut total = MomentSum::new();
+        total.add_signed(&sum, sign);
+        total.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> P::Scalar {
+        let mut length = P::Scalar::from_float(0.0);
+        for range in self.ranges.iter() {
+            length = length + contour_perimeter(&self.points[range.clone()]);
+        }
+        length
+    }
+}
+
+impl<P: FloatPointCompatible> ShapeMoments<P> for FloatFlatShapesBuffer<P> {
+    fn moments(&self) -> Option<FloatMoments<P>> {
+        let range = self.contour_ranges.iter().find(|range| !range.is_empty())?;
+        let origin = self.points[range.start];
+        let mut sum = MomentSum::new();
+        for shape_range in self.shape_ranges.iter() {
+            let contours = self.contour_ranges[shape_range.clone()]
+                .iter()
+                .map(|range| &self.points[range.clone()]);
+            sum.add_shape(contours, origin);
+        }
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> P::Scalar {
+        let mut length = P::Scalar::from_float(0.0);
+        for range in self.contour_ranges.iter() {
+            length = length + contour_perimeter(&self.points[range.clone()]);
+        }
+        length
+    }
+}
+
+/// Green's theorem sums relative to an origin, for a counter-clockwise contour:
+/// `s = 2A`, `sx = 6∫x`, `sy = 6∫y`, `sxx = 12∫x²`, `syy = 12∫y²`, `sxy = 24∫xy`.
+struct MomentSum<T> {
+    s: T,
+    sx: T,
+    sy: T,
+    sxx: T,
+    syy: T,
+    sxy: T,
+}
+
+impl<T: FloatNumber> MomentSum<T> {
+    #[inline]
+    fn new() -> Self {
+        let zero = T::from_float(0.0);
+        Self {
+            s: zero,
+            sx: zero,
+            sy: zero,
+            sxx: zero,
+            syy: zero,
+            sxy: zero,
+        }
+    }
+
+    fn add_shape<'a, P, I>(&mut self, contours: I, origin: P)
+    where
+        P: FloatPointCompatible<Scalar = T> + 'a,
+        I: Iterator<Item = &'a [P]>,
+    {
+        for (index, contour) in contours.enumerate() {
+            self.add_contour(contour, origin, index == 0);
+        }
+    }
+
+    fn add_contour<P: FloatPointCompatible<Scalar = T>>(&mut self, contour: &[P], origin: P, is_outer: bool) {
+        let sum = Self::with_contour(contour, origin);
+
+        // outer contours add and holes subtract regardless of the direction
+        let is_positive = sum.s > T::from_float(0.0);
+        let sign = if is_positive == is_outer {
+            T::from_float(1.0)
+        } else {
+            T::from_float(-1.0)
+        };
+
+        self.add_signed(&sum, sign);
+    }
+
+    /// The sums of a single contour, positive for a counter-clockwise contour.
+    fn with_contour<P: FloatPointCompatible<Scalar = T>>(contour: &[P], origin: P) -> Self {
+        let mut sum = Self::new();
+        let two = T::from_float(2.0);
+
+        let mut p0 = if let Some(p) = contour.last() {
+            *p
+        } else {
+            return sum;
+        };
+        for &p1 in contour.iter() {
+            let (x0, y0) = (p0.x() - origin.x(), p0.y() - origin.y());
+            let (x1, y1) = (p1.x() - origin.x(), p1.y() - origin.y());
+            let c = x0 * y1 - x1 * y0;
+
+            sum.s = sum.s + c;
+            sum.sx = sum.sx + (x0 + x1) * c;
+            sum.sy = sum.sy + (y0 + y1) * c;
+            sum.sxx = sum.sxx + (x0 * x0 + x0 * x1 + x1 * x1) * c;
+            sum.syy = sum.syy + (y0 * y0 + y0 * y1 + y1 * y1) * c;
+            sum.sxy = sum.sxy + (x0 * y1 + two * x0 * y0 + two * x1 * y1 + x1 * y0) * c;
+
+            p0 = p1;
+        }
+
+        sum
+    }
+
+    #[inline]
+    fn add_signed(&mut self, other: &Self, sign: T) {
+        self.s = self.s + sign * other.s;
+        self.sx = self.sx + sign * other.sx;
+        self.sy = self.sy + sign * other.sy;
+        self.sxx = self.sxx + sign * other.sxx;
+        self.syy = self.syy + sign * other.syy;
+        self.sxy = self.sxy + sign * other.sxy;
+    }
+
+    fn moments<P: FloatPointCompatible<Scalar = T>>(&self, origin: P) -> Option<FloatMoments<P>> {
+        if self.s == T::from_float(0.0) {
+            return None;
+        }
+
+        let s = self.s;
+        let area = T::from_float(0.5) * s;
+        let three_s = T::from_float(3.0) * s;
+        let cx = self.sx / three_s;
+        let cy = self.sy / three_s;
+
+        // the parallel axis theorem
+        let ixx = self.syy / T::from_float(12.0) - area * cy * cy;
+        let iyy = self.sxx / T::from_float(12.0) - area * cx * cx;
+        let ixy = self.sxy / T::from_float(24.0) - area * cx * cy;
+
+        Some(FloatMoments {
+            area,
+            centroid: P::from_xy(origin.x() + cx, origin.y() + cy),
+            ixx,
+            iyy,
+            ixy,
+        })
+    }
+}
+
+#[inline]
+fn contour_perimeter<P: FloatPointCompatible>(contour: &[P]) -> P::Scalar {
+    let mut length = P::Scalar::from_float(0.0);
+    let mut p0 = if let Some(p) = contour.last() {
+        *p
+    } else {
+        return length;
+    };
+
+    for &p1 in contour.iter() {
+        let dx = p1.x() - p0.x();
+        let dy = p1.y() - p0.y();
+        length = length + (dx * dx + dy * dy).sqrt();
+        p0 = p1;
+    }
+
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+    use crate::float::moments::ShapeMoments;
+    use crate::{path, paths};
+
+    #[test]
+    fn test_0() {
+        let shape = paths![
+            [[0.0f64, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[2.0, 1.0], [2.0, 3.0], [3.0, 3.0], [3.0, 1.0]],
+        ];
+
+        let m = shape.moments().unwrap();
+        assert_eq!(m.area, 14.0);
+        assert!((m.centroid[0] - 27.0 / 14.0).abs() < 1e-12);
+        assert_eq!(m.centroid[1], 2.0);
+        assert_eq!(shape.perimeter(), 22.0);
+
+        let rect = path![[0.0f64, 0.0], [0.0, 2.0], [4.0, 2.0], [4.0, 0.0]];
+        let m = rect.moments().unwrap();
+        assert_eq!(m.area, 8.0);
+        assert!((m.ixx - 8.0 / 3.0).abs() < 1e-12);
+        assert!((m.iyy - 32.0 / 3.0).abs() < 1e-12);
+        assert_eq!(m.ixy, 0.0);
+    }
+
+    #[test]
+    fn test_1() {
+        let shape = paths![
+            [[0.0f64, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[2.0, 1.0], [2.0, 3.0], [3.0, 3.0], [3.0, 1.0]],
+        ];
+
+        let mut contours = FloatFlatContoursBuffer::default();
+        contours.set_with_resource(&shape);
+        let mut shapes = FloatFlatShapesBuffer::default();
+        shapes.set_with_resource(&shape);
+
+        let m = shape.moments().unwrap();
+        for flat in [contours.moments().unwrap(), shapes.moments().unwrap()] {
+            assert_eq!(flat.area, m.area);
+            assert_eq!(flat.centroid, m.centroid);
+            assert_eq!((flat.ixx, flat.iyy, flat.ixy), (m.ixx, m.iyy, m.ixy));
+        }
+        assert_eq!(contours.perimeter(), shape.perimeter());
+        assert_eq!(shapes.perimeter(), shape.perimeter());
+
+        assert!(FloatFlatContoursBuffer::<[f64; 2]>::default().moments().is_none());
+    }
+
+    #[test]
+    fn test_2() {
+        // two disjoint squares are two regions, not an outer contour and a hole
+        let mut buffer = FloatFlatContoursBuffer::default();
+        buffer.add_contour(&[[0.0f64, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+        buffer.add_contour(&[[4.0, 0.0], [6.0, 0.0], [6.0, 2.0], [4.0, 2.0]]);
+
+        let m = buffer.moments().unwrap();
+        assert_eq!(m.area, 8.0);
+        assert_eq!(m.centroid, [3.0, 1.0]);
+        assert!((m.iyy - (2.0 * 8.0 / 12.0 * 2.0 + 2.0 * 4.0 * 4.0)).abs() < 1e-12);
+
+        // the same regions in the clockwise direction
+        let mut reversed = FloatFlatContoursBuffer::default();
+        reversed.add_contour(&[[0.0f64, 0.0], [0.0, 2.0], [2.0, 2.0], [2.0, 0.0]]);
+        reversed.add_contour(&[[4.0, 0.0], [4.0, 2.0], [6.0, 2.0], [6.0, 0.0]]);
+        let r = reversed.moments().unwrap();
+        assert_eq!((r.area, r.centroid), (m.area, m.centroid));
+        assert_eq!((r.ixx, r.iyy, r.ixy), (m.ixx, m.iyy, m.ixy));
+    }
+}
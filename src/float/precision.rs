@@ -0,0 +1,154 @@
+use crate::source::resource::ShapeResource;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_float::int::point::IntPoint;
+
+/// The precision loss of a float to int round-trip.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrecisionReport<T> {
+    /// The float distance between two neighbouring integer points.
+    pub grid_step: T,
+    /// The maximum distance between a point and its round-tripped copy.
+    pub max_error: T,
+    /// The number of distinct float points merged into an integer point already taken by another one.
+    pub collapsed_points: usize,
+    /// The number of checked points.
+    pub points_count: usize,
+    /// The number of skipped points outside the adapter rect.
+    pub outside_points: usize,
+}
+
+impl<T> PrecisionReport<T> {
+    /// Checks if every distinct float point keeps its own integer point.
+    #[inline]
+    pub fn is_injective(&self) -> bool {
+        self.collapsed_points == 0
+    }
+}
+
+/// A trait for measuring the precision of a `FloatPointAdapter`.
+pub trait AdapterPrecision<P: FloatPointCompatible> {
+    /// Converts every point of `resource` inside the adapter rect to int and back,
+    /// the points outside the rect are only counted.
+    ///
+    /// # Returns
+    ///
+    /// - A `PrecisionReport` with the grid step, the maximum round-trip error
+    ///   and the number of collapsed and outside points.
+    fn precision_report<R>(&self, resource: &R) -> PrecisionReport<P::Scalar>
+    where
+        R: ShapeResource<P> + ?Sized;
+}
+
+impl<P: FloatPointCompatible> AdapterPrecision<P> for FloatPointAdapter<P> {
+    fn precision_report<R>(&self, resource: &R) -> PrecisionReport<P::Scalar>
+    where
+        R: ShapeResource<P> + ?Sized,
+    {
+        let mut max_sqr_error = P::Scalar::from_float(0.0);
+        let mut points: Vec<(IntPoint, P)> = Vec::new();
+        let mut outside_points = 0;
+        for path in resource.iter_paths() {
+            for p in path.iter() {
+                if !self.rect.contains(p) {
+                    outside_points += 1;
+                    continue;
+                }
+                let int = self.float_to_int(p);
+                let back = self.int_to_float(&int);
+                let dx = back.x() - p.x();
+                let dy = back.y() - p.y();
+                max_sqr_error = max_sqr_error.max(dx * dx + dy * dy);
+                points.push((int, *p));
+            }
+        }
+
+        PrecisionReport {
+            grid_step: self.inv_scale,
+            max_error: max_sqr_error.sqrt(),
+            collapsed_points: collapsed_count(&mut points),
+            points_count: points.len(),
+            outside_points,
+        }
+    }
+}
+
+/// Counts distinct float points sharing an integer point with a preceding one.
+fn collapsed_count<P: FloatPointCompatible>(points: &mut [(IntPoint, P)]) -> usize {
+    points.sort_unstable_by(|a, b| {
+        a.0.cmp(&b.0)
+            .then(a.1.x().partial_cmp(&b.1.x()).unwrap_or(Ordering::Equal))
+            .then(a.1.y().partial_cmp(&b.1.y()).unwrap_or(Ordering::Equal))
+    });
+
+    points
+        .windows(2)
+        .filter(|w| {
+            let (a, b) = (&w[0], &w[1]);
+            a.0 == b.0 && (a.1.x() != b.1.x() || a.1.y() != b.1.y())
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::float::precision::AdapterPrecision;
+    use crate::{path, paths};
+    use alloc::vec;
+    use i_float::adapter::FloatPointAdapter;
+    use i_float::float::rect::FloatRect;
+
+    #[test]
+    fn test_0() {
+        let shapes = vec![paths![[[0.0f64, 0.0], [8.0, 0.0], [8.0, 8.0], [0.0, 8.0]]]];
+        let adapter = FloatPointAdapter::with_iter(shapes.iter().flatten().flatten());
+        let report = adapter.precision_report(&shapes);
+
+        assert_eq!(report.points_count, 4);
+        assert_eq!(report.outside_points, 0);
+        assert_eq!(report.max_error, 0.0);
+        assert!(report.is_injective());
+        assert!(report.grid_step > 0.0 && report.grid_step < 1e-7);
+    }
+
+    #[test]
+    fn test_1() {
+        let shapes = vec![paths![
+            [[0.0f64, 0.0], [0.3, 0.0], [0.3, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            [[0.1, 0.0], [0.31, 0.0], [0.0, 0.05]]
+        ]];
+        let adapter = FloatPointAdapter::with_scale(FloatRect::new(0.0, 10.0, 0.0, 10.0), 1.0);
+        let report = adapter.precision_report(&shapes);
+
+        assert_eq!(report.grid_step, 1.0);
+        assert_eq!(report.points_count, 8);
+        // [0.0, 0.0] takes the int point, [0.1, 0.0], [0.3, 0.0] and [0.31, 0.0] collapse
+        // onto [-5, -5], [0.0, 0.05] too, the duplicated [0.3, 0.0] is not counted
+        assert_eq!(report.collapsed_points, 4);
+        assert!(report.max_error > 0.3 && report.max_error < 0.32);
+
+        let contour = path![[0.0f64, 0.0], [10.0, 10.0]];
+        assert!(adapter.precision_report(&vec![vec![contour]]).is_injective());
+    }
+
+    #[test]
+    fn test_2() {
+        let adapter = FloatPointAdapter::with_scale(FloatRect::new(0.0, 10.0, 0.0, 10.0), 1.0);
+        let contour = path![
+            [0.0f64, 0.0],
+            [10.0, 10.0],
+            [1e12, 0.0],
+            [f64::NAN, 5.0],
+            [-0.5, 5.0]
+        ];
+        let report = adapter.precision_report(&vec![vec![contour]]);
+
+        assert_eq!(report.points_count, 2);
+        assert_eq!(report.outside_points, 3);
+        assert_eq!(report.max_error, 0.0);
+        assert!(report.is_injective());
+    }
+}
@@ -1,9 +1,25 @@
-use crate::base::data::Shape;
+use crate::base::data::{Shape, Shapes};
+use crate::base::direction::ContourDirection;
+use crate::flat::float::FloatFlatShapesBuffer;
+use crate::float::area::Area;
+use crate::int::reverse::direction;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
 
 pub trait ContourReverse {
     fn reverse_contours(&mut self);
 }
 
+pub trait ContourOrientation<P: FloatPointCompatible> {
+    /// Reverses contours in-place so the outer contour of every shape has `main_direction`
+    /// and all holes have the opposite one. Contours with zero area are left as is.
+    ///
+    /// # Returns
+    ///
+    /// - The number of reversed contours.
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize;
+}
+
 impl<P> ContourReverse for Shape<P> {
     #[inline]
     fn reverse_contours(&mut self) {
@@ -12,3 +28,76 @@ impl<P> ContourReverse for Shape<P> {
         }
     }
 }
+
+impl<P: FloatPointCompatible> ContourOrientation<P> for Shape<P> {
+    #[inline]
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize {
+        let mut count = 0;
+        for (index, contour) in self.iter_mut().enumerate() {
+            count += orient_contour(contour, direction(index, main_direction)) as usize;
+        }
+        count
+    }
+}
+
+impl<P: FloatPointCompatible> ContourOrientation<P> for Shapes<P> {
+    #[inline]
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize {
+        self.iter_mut()
+            .map(|shape| shape.normalize_orientation(main_direction))
+            .sum()
+    }
+}
+
+impl<P: FloatPointCompatible> ContourOrientation<P> for FloatFlatShapesBuffer<P> {
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize {
+        let mut count = 0;
+        for shape_range in self.shape_ranges.iter() {
+            let contour_ranges = &self.contour_ranges[shape_range.clone()];
+            for (index, range) in contour_ranges.iter().enumerate() {
+                let contour = &mut self.points[range.clone()];
+                count += orient_contour(contour, direction(index, main_direction)) as usize;
+            }
+        }
+        count
+    }
+}
+
+/// Returns `true` if the contour was reversed.
+#[inline]
+fn orient_contour<P: FloatPointCompatible>(contour: &mut [P], direction: ContourDirection) -> bool {
+    // a positive float area is counter-clockwise
+    let area = contour.area();
+    let zero = P::Scalar::from_float(0.0);
+    let is_clockwise = area < zero;
+    if area == zero || is_clockwise == (direction == ContourDirection::Clockwise) {
+        return false;
+    }
+    contour.reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::direction::ContourDirection;
+    use crate::float::reverse::ContourOrientation;
+    use crate::paths;
+
+    #[test]
+    fn test_0() {
+        let mut shape = paths![
+            [[-10.0, -10.0], [-10.0, 10.0], [10.0, 10.0], [10.0, -10.0]],
+            [[-5.0, -5.0], [5.0, -5.0], [5.0, 5.0], [-5.0, 5.0]],
+        ];
+
+        assert_eq!(shape.normalize_orientation(ContourDirection::CounterClockwise), 2);
+        assert_eq!(
+            shape,
+            paths![
+                [[10.0, -10.0], [10.0, 10.0], [-10.0, 10.0], [-10.0, -10.0]],
+                [[-5.0, 5.0], [5.0, 5.0], [5.0, -5.0], [-5.0, -5.0]],
+            ]
+        );
+        assert_eq!(shape.normalize_orientation(ContourDirection::CounterClockwise), 0);
+    }
+}
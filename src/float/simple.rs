@@ -1,11 +1,14 @@
 use crate::base::data::{Contour, Shape, Shapes};
-use crate::flat::float::FloatFlatContoursBuffer;
-use crate::float::adapter::{
-    BufferToInt, PathToFloat, PathToInt, ShapeToFloat, ShapeToInt, ShapesToFloat, ShapesToInt,
-};
+use crate::flat::edit::{rewrite_contours, rewrite_shapes};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use crate::float::adapter::{PathToFloat, PathToInt, ShapeToFloat, ShapeToInt, ShapesToFloat, ShapesToInt};
+use crate::int::clean::ContourCleaner;
+use crate::int::reduce::{Reduce as IntReduce, ReduceContour, ReduceMethod, ReduceOptions};
 use crate::int::simple::Simplify as IntSimplify;
+use alloc::vec::Vec;
 use i_float::adapter::FloatPointAdapter;
 use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
 
 /// A trait that provides methods for simplifying complex geometrical structures.
 pub trait SimplifyContour<P: FloatPointCompatible> {
@@ -67,21 +70,234 @@ impl<P: FloatPointCompatible> SimplifyContour<P> for Shapes<P> {
 }
 
 impl<P: FloatPointCompatible> SimplifyContour<P> for FloatFlatContoursBuffer<P> {
+    /// Simplifies every contour in place, degenerate contours are removed.
     fn simplify_contour(&mut self, adapter: &FloatPointAdapter<P>) -> bool {
-        let int_buffer = self.to_int(adapter);
-        self.clear_and_reserve(int_buffer.ranges.len(), int_buffer.ranges.len());
-        let mut changed = false;
-
-        for mut contour in int_buffer.to_contours().into_iter() {
-            changed |= contour.simplify_contour();
-            if !contour.is_empty() {
-                self.add_contour_iter(contour.iter().map(|p| adapter.int_to_float(p)));
-            }
+        let mut cleaner = ContourCleaner::default();
+        let mut int_contour = Vec::new();
+        rewrite_contours(&mut self.points, &mut self.ranges, |_, contour| {
+            int_contour.clear();
+            int_contour.extend(contour.iter().map(|p| adapter.float_to_int(p)));
+            cleaner.edit(&mut int_contour).into_float(&int_contour, adapter)
+        })
+    }
+}
+
+impl<P: FloatPointCompatible> SimplifyContour<P> for FloatFlatShapesBuffer<P> {
+    /// Simplifies every contour in place, degenerate holes are removed
+    /// and a shape is removed if its outer contour is degenerate.
+    fn simplify_contour(&mut self, adapter: &FloatPointAdapter<P>) -> bool {
+        let mut cleaner = ContourCleaner::default();
+        let mut int_contour = Vec::new();
+        rewrite_shapes(
+            &mut self.points,
+            &mut self.contour_ranges,
+            &mut self.shape_ranges,
+            |_, _, contour| {
+                int_contour.clear();
+                int_contour.extend(contour.iter().map(|p| adapter.float_to_int(p)));
+                cleaner.edit(&mut int_contour).into_float(&int_contour, adapter)
+            },
+        )
+    }
+}
+
+/// A trait for tolerance-based point reduction of float structures.
+///
+/// The tolerance of `ReduceOptions` is given in float units, every contour is treated as closed.
+pub trait ReduceFloatContour<P: FloatPointCompatible> {
+    /// Reduces the structure in-place, degenerate contours are removed.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if any point was removed.
+    /// - `false` if the structure was not modified.
+    fn reduce_contour(&mut self, options: &ReduceOptions, adapter: &FloatPointAdapter<P>) -> bool;
+}
+
+/// A trait for tolerance-based point reduction of an open float path.
+pub trait ReducePath<P: FloatPointCompatible> {
+    /// Reduces the open path in-place, the first and the last points are always kept.
+    /// The tolerance of `method` is given in float units.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if any point was removed.
+    /// - `false` if the path was not modified.
+    fn reduce_path(&mut self, method: ReduceMethod, adapter: &FloatPointAdapter<P>) -> bool;
+}
+
+impl<P: FloatPointCompatible> ReduceFloatContour<P> for Contour<P> {
+    fn reduce_contour(&mut self, options: &ReduceOptions, adapter: &FloatPointAdapter<P>) -> bool {
+        let mut int_contour = self.to_int(adapter);
+        if !int_contour.reduce(&int_options(options, adapter)) {
+            return false;
+        }
+
+        if int_contour.is_empty() {
+            self.clear();
+        } else {
+            *self = int_contour.to_float(adapter);
         }
+        true
+    }
+}
 
-        if !changed {
+impl<P: FloatPointCompatible> ReduceFloatContour<P> for Shape<P> {
+    fn reduce_contour(&mut self, options: &ReduceOptions, adapter: &FloatPointAdapter<P>) -> bool {
+        let mut int_shape = self.to_int(adapter);
+        if !int_shape.reduce(&int_options(options, adapter)) {
             return false;
         }
+
+        if int_shape.is_empty() {
+            self.clear();
+        } else {
+            *self = int_shape.to_float(adapter);
+        }
         true
     }
 }
+
+impl<P: FloatPointCompatible> ReduceFloatContour<P> for Shapes<P> {
+    fn reduce_contour(&mut self, options: &ReduceOptions, adapter: &FloatPointAdapter<P>) -> bool {
+        let mut int_shapes = self.to_int(adapter);
+        if !int_shapes.reduce(&int_options(options, adapter)) {
+            return false;
+        }
+
+        if int_shapes.is_empty() {
+            self.clear();
+        } else {
+            *self = int_shapes.to_float(adapter);
+        }
+        true
+    }
+}
+
+impl<P: FloatPointCompatible> ReducePath<P> for Contour<P> {
+    fn reduce_path(&mut self, method: ReduceMethod, adapter: &FloatPointAdapter<P>) -> bool {
+        let int_path = self.to_int(adapter);
+        let reduced = int_path.reduced_path(int_method(method, adapter));
+        if reduced.len() == int_path.len() {
+            return false;
+        }
+
+        *self = reduced.to_float(adapter);
+        true
+    }
+}
+
+#[inline]
+fn int_options<P: FloatPointCompatible>(
+    options: &ReduceOptions,
+    adapter: &FloatPointAdapter<P>,
+) -> ReduceOptions {
+    ReduceOptions {
+        method: int_method(options.method, adapter),
+        preserve_topology: options.preserve_topology,
+    }
+}
+
+#[inline]
+fn int_method<P: FloatPointCompatible>(method: ReduceMethod, adapter: &FloatPointAdapter<P>) -> ReduceMethod {
+    let scale = adapter.dir_scale.to_f64();
+    match method {
+        ReduceMethod::DouglasPeucker { max_distance } => ReduceMethod::DouglasPeucker {
+            max_distance: max_distance * scale,
+        },
+        ReduceMethod::VisvalingamWhyatt { min_area } => ReduceMethod::VisvalingamWhyatt {
+            min_area: min_area * scale * scale,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::float::FloatFlatContoursBuffer;
+    use crate::float::simple::{ReduceFloatContour, ReducePath, SimplifyContour};
+    use crate::int::reduce::{ReduceMethod, ReduceOptions};
+    use crate::{path, paths};
+    use i_float::adapter::FloatPointAdapter;
+
+    #[test]
+    fn test_0() {
+        // noisy square
+        let mut contour = path![
+            [0.0f64, 0.0],
+            [5.0, 0.5],
+            [10.0, 0.0],
+            [10.5, 5.0],
+            [10.0, 10.0],
+            [5.0, 9.5],
+            [0.0, 10.0],
+            [-0.5, 5.0]
+        ];
+        let adapter = FloatPointAdapter::with_iter(contour.iter());
+        let options = ReduceOptions {
+            method: ReduceMethod::DouglasPeucker { max_distance: 1.0 },
+            preserve_topology: false,
+        };
+
+        assert!(contour.reduce_contour(&options, &adapter));
+        assert_eq!(contour.len(), 4);
+        for (p, q) in contour
+            .iter()
+            .zip([[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]])
+        {
+            assert!((p[0] - q[0]).abs() < 1e-6 && (p[1] - q[1]).abs() < 1e-6);
+        }
+
+        // nothing is left to remove
+        assert!(!contour.reduce_contour(&options, &adapter));
+    }
+
+    #[test]
+    fn test_1() {
+        let mut shape = paths![
+            [[0.0f64, 0.0], [5.0, 0.5], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[4.0, 4.0], [4.0, 6.0], [6.0, 6.0], [6.0, 4.0]],
+        ];
+        let adapter = FloatPointAdapter::with_iter(shape.iter().flatten());
+        let options = ReduceOptions {
+            method: ReduceMethod::VisvalingamWhyatt { min_area: 5.0 },
+            preserve_topology: true,
+        };
+
+        // the bump area is 2.5, the hole is kept as a triangle
+        assert!(shape.reduce_contour(&options, &adapter));
+        assert_eq!(shape[0].len(), 4);
+        assert_eq!(shape[1].len(), 3);
+    }
+
+    #[test]
+    fn test_2() {
+        let mut path = path![[0.0f64, 0.0], [5.0, 0.5], [10.0, 0.0], [15.0, -0.5], [20.0, 0.0]];
+        let adapter = FloatPointAdapter::with_iter(path.iter());
+
+        let method = ReduceMethod::DouglasPeucker { max_distance: 1.0 };
+        assert!(path.reduce_path(method, &adapter));
+        assert_eq!(path.len(), 2);
+        assert!((path[1][0] - 20.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_3() {
+        let mut contours = paths![
+            [[0.0f64, 0.0], [5.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+            [[20.0, 0.0], [25.0, 0.0], [30.0, 0.0]],
+            [[20.0, 10.0], [30.0, 10.0], [30.0, 20.0], [20.0, 20.0]],
+        ];
+        let adapter = FloatPointAdapter::with_iter(contours.iter().flatten());
+
+        let mut buffer = FloatFlatContoursBuffer::default();
+        buffer.set_with_resource(&contours);
+
+        assert!(buffer.simplify_contour(&adapter));
+        assert!(contours.simplify_contour(&adapter));
+        assert_eq!(buffer.to_contours(), contours);
+        assert_eq!(buffer.ranges, [0..4, 4..8]);
+        assert_eq!(buffer.points.len(), 8);
+
+        assert!(!buffer.simplify_contour(&adapter));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::base::data::{Shape, Shapes};
+use crate::flat::buffer::FlatShapesBuffer;
+use crate::flat::float::FloatFlatShapesBuffer;
+use crate::int::clean::ContourCleaner;
+use crate::int::shape::{IntShape, IntShapes};
+use alloc::vec::Vec;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_float::int::point::IntPoint;
+
+/// A trait for snap rounding to a regular grid.
+pub trait SnapToGrid<P: FloatPointCompatible> {
+    /// Snaps every point to the nearest node of a grid with the cell size `grid_size`,
+    /// then removes duplicate points, spikes and collinear points, so every contour is clean on the grid.
+    /// Degenerate holes are removed and a shape is removed if its outer contour is degenerate.
+    ///
+    /// The grid is computed exactly in grid units, which must fit into `i32`.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the structure was snapped.
+    /// - `false` if `grid_size` is not positive or a grid coordinate overflows `i32`,
+    ///   the structure is left unchanged.
+    fn snap_to_grid(&mut self, grid_size: P::Scalar) -> bool;
+}
+
+impl<P: FloatPointCompatible> SnapToGrid<P> for Shape<P> {
+    fn snap_to_grid(&mut self, grid_size: P::Scalar) -> bool {
+        let grid = if let Some(grid) = Grid::new(grid_size) {
+            grid
+        } else {
+            return false;
+        };
+
+        let mut int_shape = if let Some(shape) = grid.to_int_shape(self) {
+            shape
+        } else {
+            return false;
+        };
+
+        ContourCleaner::default().clean_shape(&mut int_shape);
+        *self = grid.to_float_shape(&int_shape);
+        true
+    }
+}
+
+impl<P: FloatPointCompatible> SnapToGrid<P> for Shapes<P> {
+    fn snap_to_grid(&mut self, grid_size: P::Scalar) -> bool {
+        let grid = if let Some(grid) = Grid::new(grid_size) {
+            grid
+        } else {
+            return false;
+        };
+
+        let mut int_shapes = if let Some(shapes) = self
+            .iter()
+            .map(|shape| grid.to_int_shape(shape))
+            .collect::<Option<IntShapes>>()
+        {
+            shapes
+        } else {
+            return false;
+        };
+
+        ContourCleaner::default().clean_shapes(&mut int_shapes);
+        *self = int_shapes
+            .iter()
+            .map(|shape| grid.to_float_shape(shape))
+            .collect();
+        true
+    }
+}
+
+impl<P: FloatPointCompatible> SnapToGrid<P> for FloatFlatShapesBuffer<P> {
+    fn snap_to_grid(&mut self, grid_size: P::Scalar) -> bool {
+        let grid = if let Some(grid) = Grid::new(grid_size) {
+            grid
+        } else {
+            return false;
+        };
+
+        // unused points are skipped, the shape ranges index contours and stay valid
+        let count = self.contour_ranges.iter().map(|range| range.len()).sum();
+        let mut points = Vec::with_capacity(count);
+        let mut contour_ranges = Vec::with_capacity(self.contour_ranges.len());
+        for range in self.contour_ranges.iter() {
+            let start = points.len();
+            for p in self.points[range.clone()].iter() {
+                if let Some(point) = grid.to_int(p) {
+                    points.push(point);
+                } else {
+                    return false;
+                }
+            }
+            contour_ranges.push(start..points.len());
+        }
+
+        let mut int_buffer = FlatShapesBuffer {
+            points,
+            contour_ranges,
+            shape_ranges: core::mem::take(&mut self.shape_ranges),
+        };
+        ContourCleaner::default().clean_flat_shapes(&mut int_buffer);
+
+        self.points.clear();
+        self.points
+            .extend(int_buffer.points.iter().map(|&p| grid.to_float::<P>(p)));
+        self.contour_ranges = int_buffer.contour_ranges;
+        self.shape_ranges = int_buffer.shape_ranges;
+        true
+    }
+}
+
+struct Grid<T> {
+    size: T,
+    inv_size: f64,
+}
+
+impl<T: FloatNumber> Grid<T> {
+    #[inline]
+    fn new(size: T) -> Option<Self> {
+        let inv_size = 1.0 / size.to_f64();
+        if size > T::from_float(0.0) && inv_size.is_finite() {
+            Some(Self { size, inv_size })
+        } else {
+            None
+        }
+    }
+
+    /// The grid coordinates of the nearest node.
+    #[inline]
+    fn to_int<P: FloatPointCompatible<Scalar = T>>(&self, p: &P) -> Option<IntPoint> {
+        let x = snap(p.x().to_f64() * self.inv_size)?;
+        let y = snap(p.y().to_f64() * self.inv_size)?;
+        Some(IntPoint::new(x, y))
+    }
+
+    #[inline]
+    fn to_float<P: FloatPointCompatible<Scalar = T>>(&self, p: IntPoint) -> P {
+        P::from_xy(T::from_i32(p.x) * self.size, T::from_i32(p.y) * self.size)
+    }
+
+    #[inline]
+    fn to_int_shape<P: FloatPointCompatible<Scalar = T>>(&self, shape: &[Vec<P>]) -> Option<IntShape> {
+        shape
+            .iter()
+            .map(|contour| contour.iter().map(|p| self.to_int(p)).collect())
+            .collect()
+    }
+
+    #[inline]
+    fn to_float_shape<P: FloatPointCompatible<Scalar = T>>(&self, shape: &IntShape) -> Shape<P> {
+        shape
+            .iter()
+            .map(|contour| contour.iter().map(|&p| self.to_float(p)).collect())
+            .collect()
+    }
+}
+
+/// Rounds half away from zero.
+#[inline]
+fn snap(value: f64) -> Option<i32> {
+    // NaN fails both checks
+    if !(value >= i32::MIN as f64 - 0.5 && value <= i32::MAX as f64 + 0.5) {
+        return None;
+    }
+    let rounded = if value >= 0.0 { value + 0.5 } else { value - 0.5 };
+    i32::try_from(rounded as i64).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::float::FloatFlatShapesBuffer;
+    use crate::float::snap::SnapToGrid;
+    use crate::{path, paths};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_0() {
+        let mut shape = paths![
+            [
+                [0.0f64, 0.0],
+                [1e-9, 1e-9],
+                [2.04, 0.0],
+                [4.0, 0.01],
+                [4.0, 4.0],
+                [0.0, 3.98]
+            ],
+            [[1.0, 1.0], [1.04, 1.02], [1.01, 1.03]],
+            [[2.0, 2.0], [2.0, 3.0], [3.0, 3.0], [2.96, 1.99]],
+        ];
+
+        let mut buffer = FloatFlatShapesBuffer::default();
+        buffer.set_with_resource(&shape);
+
+        assert!(shape.snap_to_grid(0.1));
+        let expected = paths![
+            [[0.0f64, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[2.0, 2.0], [2.0, 3.0], [3.0, 3.0], [3.0, 2.0]],
+        ];
+        assert_eq!(shape.len(), 2);
+        for (contour, expected) in shape.iter().zip(expected.iter()) {
+            assert_eq!(contour.len(), expected.len());
+            for (p, e) in contour.iter().zip(expected.iter()) {
+                assert!((p[0] - e[0]).abs() < 1e-12 && (p[1] - e[1]).abs() < 1e-12);
+            }
+        }
+
+        assert!(buffer.snap_to_grid(0.1));
+        assert_eq!(buffer.to_shapes(), vec![shape]);
+    }
+
+    #[test]
+    fn test_1() {
+        let mut contour = vec![path![[0.0f64, 0.0], [1e12, 0.0], [0.0, 1.0]]];
+        let origin = contour.clone();
+        assert!(!contour.snap_to_grid(1e-3));
+        assert!(!contour.snap_to_grid(0.0));
+        assert_eq!(contour, origin);
+    }
+
+    #[test]
+    fn test_2() {
+        // grid coordinates near the i32 limits are valid
+        let mut shape = vec![path![[-2e9f64, -2e9], [0.0, -2e9], [2e9, -2e9], [2e9, 2e9]]];
+        assert!(shape.snap_to_grid(1.0));
+        assert_eq!(shape, vec![path![[-2e9, -2e9], [2e9, -2e9], [2e9, 2e9]]]);
+
+        let mut line = vec![path![[-2e9f64, -2e9], [0.0, 0.0], [2e9, 2e9]]];
+        assert!(line.snap_to_grid(1.0));
+        assert!(line.is_empty());
+    }
+
+    #[test]
+    fn test_3() {
+        let mut shapes = vec![
+            vec![path![[0.0f64, 0.0], [4.02, 0.0], [4.0, 4.0], [0.0, 4.0]]],
+            vec![path![[10.0f64, 0.0], [14.0, 0.0], [14.0, 3.97], [10.0, 4.0]]],
+        ];
+
+        // an unused point which does not fit into the grid
+        let points: Vec<_> = shapes[0][0]
+            .iter()
+            .chain([[1e12, 1e12]].iter())
+            .chain(shapes[1][0].iter())
+            .copied()
+            .collect();
+        let mut buffer = FloatFlatShapesBuffer::default();
+        buffer.set_flat(&points, &[0..4, 5..9], &[0..1, 1..2]);
+
+        assert!(buffer.snap_to_grid(0.1));
+        assert!(shapes.snap_to_grid(0.1));
+        assert_eq!(buffer.to_shapes(), shapes);
+        assert_eq!(buffer.points.len(), 8);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::base::data::{Contour, Shape, Shapes};
+use crate::flat::float::{FloatFlatContoursBuffer, FloatFlatShapesBuffer};
+use crate::source::adapter::AdapterBuilder;
+use core::ops::Range;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_float::float::rect::FloatRect;
+
+/// A 2D affine transform matrix.
+///
+/// A point is transformed as:
+/// - `x' = m00 * x + m01 * y + tx`
+/// - `y' = m10 * x + m11 * y + ty`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D<T: FloatNumber> {
+    pub m00: T,
+    pub m01: T,
+    pub m10: T,
+    pub m11: T,
+    pub tx: T,
+    pub ty: T,
+}
+
+impl<T: FloatNumber> Transform2D<T> {
+    #[inline]
+    pub fn new(m00: T, m01: T, m10: T, m11: T, tx: T, ty: T) -> Self {
+        Self {
+            m00,
+            m01,
+            m10,
+            m11,
+            tx,
+            ty,
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::scale(T::from_float(1.0), T::from_float(1.0))
+    }
+
+    #[inline]
+    pub fn translation(tx: T, ty: T) -> Self {
+        let zero = T::from_float(0.0);
+        let one = T::from_float(1.0);
+        Self::new(one, zero, zero, one, tx, ty)
+    }
+
+    #[inline]
+    pub fn scale(sx: T, sy: T) -> Self {
+        let zero = T::from_float(0.0);
+        Self::new(sx, zero, zero, sy, zero, zero)
+    }
+
+    /// Counter-clockwise rotation around the origin by `angle` radians.
+    #[inline]
+    pub fn rotation(angle: T) -> Self {
+        let zero = T::from_float(0.0);
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, -sin, sin, cos, zero, zero)
+    }
+
+    /// Returns the transform which applies `self` first and then `next`.
+    #[inline]
+    pub fn then(&self, next: &Self) -> Self {
+        Self {
+            m00: next.m00 * self.m00 + next.m01 * self.m10,
+            m01: next.m00 * self.m01 + next.m01 * self.m11,
+            m10: next.m10 * self.m00 + next.m11 * self.m10,
+            m11: next.m10 * self.m01 + next.m11 * self.m11,
+            tx: next.m00 * self.tx + next.m01 * self.ty + next.tx,
+            ty: next.m10 * self.tx + next.m11 * self.ty + next.ty,
+        }
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.m00 * self.m11 - self.m01 * self.m10
+    }
+
+    /// Checks if the transform flips the contour direction.
+    #[inline]
+    pub fn is_reflection(&self) -> bool {
+        self.determinant() < T::from_float(0.0)
+    }
+
+    #[inline]
+    pub fn apply<P: FloatPointCompatible<Scalar = T>>(&self, p: &P) -> P {
+        let x = self.m00 * p.x() + self.m01 * p.y() + self.tx;
+        let y = self.m10 * p.x() + self.m11 * p.y() + self.ty;
+        P::from_xy(x, y)
+    }
+
+    /// The bounding box of the transformed rect.
+    #[inline]
+    pub fn apply_rect(&self, rect: &FloatRect<T>) -> FloatRect<T> {
+        let x = [rect.min_x, rect.max_x];
+        let y = [rect.min_y, rect.max_y];
+
+        let mut min_x = T::MAX;
+        let mut max_x = T::MIN;
+        let mut min_y = T::MAX;
+        let mut max_y = T::MIN;
+        for &xi in x.iter() {
+            for &yi in y.iter() {
+                let tx = self.m00 * xi + self.m01 * yi + self.tx;
+                let ty = self.m10 * xi + self.m11 * yi + self.ty;
+                min_x = min_x.min(tx);
+                max_x = max_x.max(tx);
+                min_y = min_y.min(ty);
+                max_y = max_y.max(ty);
+            }
+        }
+
+        FloatRect::new(min_x, max_x, min_y, max_y)
+    }
+}
+
+/// A trait for applying a `Transform2D`.
+///
+/// Contours are reversed if the determinant is negative, so outer contours and holes keep their direction.
+pub trait Transform<P: FloatPointCompatible> {
+    /// Transforms the structure in-place.
+    fn transform(&mut self, matrix: &Transform2D<P::Scalar>);
+
+    /// Returns a transformed copy.
+    fn transformed(&self, matrix: &Transform2D<P::Scalar>) -> Self;
+
+    /// Transforms the structure in-place and replaces `adapter` with the one
+    /// covering the transformed bounds of its rect.
+    ///
+    /// The adapter keeps its `dir_scale`, a new scale is derived from the bounds
+    /// only if the transformed bounds no longer fit into `i32` with it.
+    #[inline]
+    fn transform_with_adapter(
+        &mut self,
+        matrix: &Transform2D<P::Scalar>,
+        adapter: &mut FloatPointAdapter<P>,
+    ) {
+        self.transform(matrix);
+        let rect = matrix.apply_rect(&adapter.rect);
+        *adapter = AdapterBuilder::new()
+            .add_rect(&rect)
+            .scale(adapter.dir_scale.to_f64())
+            .try_build()
+            .unwrap_or_else(|| FloatPointAdapter::new(rect));
+    }
+}
+
+impl<P: FloatPointCompatible> Transform<P> for Contour<P> {
+    #[inline]
+    fn transform(&mut self, matrix: &Transform2D<P::Scalar>) {
+        apply(self, matrix);
+    }
+
+    #[inline]
+    fn transformed(&self, matrix: &Transform2D<P::Scalar>) -> Self {
+        let mut contour = self.clone();
+        contour.transform(matrix);
+        contour
+    }
+}
+
+impl<P: FloatPointCompatible> Transform<P> for Shape<P> {
+    #[inline]
+    fn transform(&mut self, matrix: &Transform2D<P::Scalar>) {
+        for contour in self.iter_mut() {
+            apply(contour, matrix);
+        }
+    }
+
+    #[inline]
+    fn transformed(&self, matrix: &Transform2D<P::Scalar>) -> Self {
+        let mut shape = self.clone();
+        shape.transform(matrix);
+        shape
+    }
+}
+
+impl<P: FloatPointCompatible> Transform<P> for Shapes<P> {
+    #[inline]
+    fn transform(&mut self, matrix: &Transform2D<P::Scalar>) {
+        for contour in self.iter_mut().flatten() {
+            apply(contour, matrix);
+        }
+    }
+
+    #[inline]
+    fn transformed(&self, matrix: &Transform2D<P::Scalar>) -> Self {
+        let mut shapes = self.clone();
+        shapes.transform(matrix);
+        shapes
+    }
+}
+
+impl<P: FloatPointCompatible> Transform<P> for FloatFlatContoursBuffer<P> {
+    #[inline]
+    fn transform(&mut self, matrix: &Transform2D<P::Scalar>) {
+        apply_flat(&mut self.points, &self.ranges, matrix);
+    }
+
+    #[inline]
+    fn transformed(&self, matrix: &Transform2D<P::Scalar>) -> Self {
+        let mut buffer = self.clone();
+        buffer.transform(matrix);
+        buffer
+    }
+}
+
+impl<P: FloatPointCompatible> Transform<P> for FloatFlatShapesBuffer<P> {
+    #[inline]
+    fn transform(&mut self, matrix: &Transform2D<P::Scalar>) {
+        apply_flat(&mut self.points, &self.contour_ranges, matrix);
+    }
+
+    #[inline]
+    fn transformed(&self, matrix: &Transform2D<P::Scalar>) -> Self {
+        let mut buffer = self.clone();
+        buffer.transform(matrix);
+        buffer
+    }
+}
+
+#[inline]
+fn apply<P: FloatPointCompatible>(points: &mut [P], matrix: &Transform2D<P::Scalar>) {
+    for p in points.iter_mut() {
+        *p = matrix.apply(p);
+    }
+    if matrix.is_reflection() {
+        points.reverse();
+    }
+}
+
+#[inline]
+fn apply_flat<P: FloatPointCompatible>(
+    points: &mut [P],
+    ranges: &[Range<usize>],
+    matrix: &Transform2D<P::Scalar>,
+) {
+    // only the points covered by the ranges are geometry
+    for range in ranges.iter() {
+        let contour = &mut points[range.clone()];
+        for p in contour.iter_mut() {
+            *p = matrix.apply(p);
+        }
+        if matrix.is_reflection() {
+            contour.reverse();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::data::Shape;
+    use crate::flat::float::FloatFlatShapesBuffer;
+    use crate::float::area::Area;
+    use crate::float::transform::{Transform, Transform2D};
+    use crate::source::adapter::AdapterBuilder;
+    use crate::{path, paths};
+    use i_float::adapter::FloatPointAdapter;
+
+    #[test]
+    fn test_0() {
+        let m = Transform2D::translation(1.0, 2.0).then(&Transform2D::scale(2.0, 3.0));
+        assert_eq!(m.apply(&[1.0, 1.0]), [4.0, 9.0]);
+
+        let r = Transform2D::rotation(core::f64::consts::FRAC_PI_2);
+        let p = r.apply(&[1.0, 0.0]);
+        assert!(p[0].abs() < 1e-12 && (p[1] - 1.0).abs() < 1e-12);
+        assert!(!r.is_reflection());
+    }
+
+    #[test]
+    fn test_1() {
+        let shape = paths![
+            [[0.0f64, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[1.0, 1.0], [1.0, 3.0], [3.0, 3.0], [3.0, 1.0]],
+        ];
+
+        let mirror = Transform2D::scale(-1.0, 1.0);
+        let result = shape.transformed(&mirror);
+        assert!(result[0].area() > 0.0);
+        assert!(result[1].area() < 0.0);
+        assert_eq!(result[0][0], [0.0, 4.0]);
+
+        let mut buffer = FloatFlatShapesBuffer::default();
+        buffer.set_with_resource(&shape);
+        let mut adapter = FloatPointAdapter::with_iter(buffer.points.iter());
+        buffer.transform_with_adapter(&Transform2D::scale(-10.0, 10.0), &mut adapter);
+
+        let expected: Shape<[f64; 2]> = shape.transformed(&Transform2D::scale(-10.0, 10.0));
+        assert_eq!(buffer.to_shapes(), [expected]);
+        assert_eq!(adapter.rect.min_x, -40.0);
+        assert_eq!(adapter.rect.max_y, 40.0);
+    }
+
+    #[test]
+    fn test_2() {
+        let mut contour = path![[0.0f64, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]];
+        let mut adapter = AdapterBuilder::new().add_points(&contour).scale(1024.0).build();
+
+        let m =
+            Transform2D::rotation(core::f64::consts::FRAC_PI_4).then(&Transform2D::translation(100.0, 0.0));
+        contour.transform_with_adapter(&m, &mut adapter);
+        assert_eq!(adapter.dir_scale, 1024.0);
+        assert!((adapter.rect.min_x - (100.0 - 8.0f64.sqrt())).abs() < 1e-12);
+
+        // the fixed scale does not fit the grown bounds, so a new one is derived
+        contour.transform_with_adapter(&Transform2D::scale(1e7, 1e7), &mut adapter);
+        assert!(adapter.dir_scale < 1024.0);
+        assert!(
+            AdapterBuilder::<[f64; 2]>::new()
+                .add_rect(&adapter.rect)
+                .scale(adapter.dir_scale)
+                .try_build()
+                .is_some()
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::view::ShapeView;
 use crate::int::path::ContourExtension;
 use crate::int::shape::{IntContour, IntShape};
 use i_float::int::point::IntPoint;
@@ -7,6 +9,20 @@ pub trait Area {
     fn area(&self) -> i64;
 }
 
+/// Overflow-safe area computation.
+///
+/// Unlike [`Area`], which accumulates with wrapping `i64` arithmetic, the sum is kept in `i128`,
+/// so the result is exact for any `i32` coordinates.
+pub trait ExactArea {
+    /// The exact double area.
+    /// - Returns: A positive value if the contours are clockwise ordered and negative otherwise.
+    fn exact_area_two(&self) -> i128;
+
+    /// The double area if it fits into `i64`.
+    /// - Returns: `None` if the exact value overflows `i64`.
+    fn checked_area_two(&self) -> Option<i64>;
+}
+
 impl Area for [IntPoint] {
     #[inline]
     fn area_two(&self) -> i64 {
@@ -51,10 +67,118 @@ impl Area for [IntShape] {
     }
 }
 
+impl Area for ShapeView<'_> {
+    #[inline]
+    fn area_two(&self) -> i64 {
+        let mut s: i64 = 0;
+        for contour in self.contours() {
+            s = s.wrapping_add(contour.area_two())
+        }
+        s
+    }
+
+    #[inline]
+    fn area(&self) -> i64 {
+        self.area_two() / 2
+    }
+}
+
+impl ExactArea for [IntPoint] {
+    fn exact_area_two(&self) -> i128 {
+        let mut p0 = if let Some(&p) = self.last() {
+            p
+        } else {
+            return 0;
+        };
+        let mut area: i128 = 0;
+
+        for &p1 in self.iter() {
+            let a = (p1.x as i64) * (p0.y as i64);
+            let b = (p1.y as i64) * (p0.x as i64);
+            area += a as i128 - b as i128;
+            p0 = p1;
+        }
+
+        area
+    }
+
+    #[inline]
+    fn checked_area_two(&self) -> Option<i64> {
+        i64::try_from(self.exact_area_two()).ok()
+    }
+}
+
+impl ExactArea for [IntContour] {
+    #[inline]
+    fn exact_area_two(&self) -> i128 {
+        self.iter().map(|contour| contour.exact_area_two()).sum()
+    }
+
+    #[inline]
+    fn checked_area_two(&self) -> Option<i64> {
+        i64::try_from(self.exact_area_two()).ok()
+    }
+}
+
+impl ExactArea for [IntShape] {
+    #[inline]
+    fn exact_area_two(&self) -> i128 {
+        self.iter().map(|shape| shape.exact_area_two()).sum()
+    }
+
+    #[inline]
+    fn checked_area_two(&self) -> Option<i64> {
+        i64::try_from(self.exact_area_two()).ok()
+    }
+}
+
+impl ExactArea for FlatContoursBuffer {
+    #[inline]
+    fn exact_area_two(&self) -> i128 {
+        self.ranges
+            .iter()
+            .map(|range| self.points[range.clone()].exact_area_two())
+            .sum()
+    }
+
+    #[inline]
+    fn checked_area_two(&self) -> Option<i64> {
+        i64::try_from(self.exact_area_two()).ok()
+    }
+}
+
+impl ExactArea for FlatShapesBuffer {
+    #[inline]
+    fn exact_area_two(&self) -> i128 {
+        self.contour_ranges
+            .iter()
+            .map(|range| self.points[range.clone()].exact_area_two())
+            .sum()
+    }
+
+    #[inline]
+    fn checked_area_two(&self) -> Option<i64> {
+        i64::try_from(self.exact_area_two()).ok()
+    }
+}
+
+impl ExactArea for ShapeView<'_> {
+    #[inline]
+    fn exact_area_two(&self) -> i128 {
+        self.contours().map(|contour| contour.exact_area_two()).sum()
+    }
+
+    #[inline]
+    fn checked_area_two(&self) -> Option<i64> {
+        i64::try_from(self.exact_area_two()).ok()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::int::area::Area;
-    use crate::int_path;
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::int::area::{Area, ExactArea};
+    use crate::{int_path, int_shape};
 
     #[test]
     fn test_0() {
@@ -63,4 +187,38 @@ mod tests {
         let area = square.area_two();
         assert_eq!(area, -8);
     }
+
+    #[test]
+    fn test_exact_0() {
+        let square = int_path![[-1, -1], [1, -1], [1, 1], [-1, 1],];
+
+        assert_eq!(square.exact_area_two(), -8);
+        assert_eq!(square.checked_area_two(), Some(-8));
+    }
+
+    #[test]
+    fn test_exact_1() {
+        let max = i32::MAX;
+        let min = i32::MIN;
+        let square = int_path![[min, min], [min, max], [max, max], [max, min],];
+
+        let side = max as i128 - min as i128;
+        assert_eq!(square.exact_area_two(), 2 * side * side);
+        assert_eq!(square.checked_area_two(), None);
+    }
+
+    #[test]
+    fn test_exact_2() {
+        let shape = int_shape![
+            [[-10, -10], [-10, 10], [10, 10], [10, -10],],
+            [[-5, -5], [5, -5], [5, 5], [-5, 5],],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shape(&shape);
+
+        assert_eq!(shape.exact_area_two(), 600);
+        assert_eq!(buffer.exact_area_two(), 600);
+        assert_eq!(buffer.checked_area_two(), Some(600));
+    }
 }
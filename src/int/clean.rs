@@ -0,0 +1,358 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::edit::{ContourEdit, rewrite_contours, rewrite_shapes};
+use crate::int::exact::orient;
+use crate::int::shape::{IntContour, IntShape, IntShapes};
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+
+/// A reusable context for cleaning contours without allocations.
+///
+/// A vertex is removed if its adjacent edges are collinear, which covers duplicate points,
+/// points lying on a straight line and spikes in a single pass.
+/// A contour with fewer than 3 remaining points is degenerate.
+///
+/// The scratch buffers grow to the largest contour and are reused across calls.
+#[derive(Default)]
+pub struct ContourCleaner {
+    nodes: Vec<Node>,
+    validated: Vec<bool>,
+}
+
+impl ContourCleaner {
+    /// Appends the cleaned contour to `output`.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the cleaned contour was appended.
+    /// - `false` if the contour is degenerate, `output` is left unchanged.
+    pub fn clean_into(&mut self, contour: &[IntPoint], output: &mut Vec<IntPoint>) -> bool {
+        let count = self.mark(contour);
+        if count == 0 {
+            return false;
+        }
+
+        output.reserve(count);
+        output.extend(
+            contour
+                .iter()
+                .zip(self.validated.iter())
+                .filter(|&(_, &is_kept)| is_kept)
+                .map(|(&p, _)| p),
+        );
+        true
+    }
+
+    /// Cleans the contour in place, a degenerate contour is cleared.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if any point was removed.
+    /// - `false` if the contour was not modified.
+    pub fn clean_contour(&mut self, contour: &mut IntContour) -> bool {
+        let n = contour.len();
+        let count = self.clean_slice(contour);
+        contour.truncate(count);
+        count < n
+    }
+
+    /// Cleans all contours of the shape in place, degenerate holes are removed
+    /// and the shape is cleared if its outer contour is degenerate.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the shape was modified.
+    /// - `false` if the shape was not modified.
+    pub fn clean_shape(&mut self, shape: &mut IntShape) -> bool {
+        let mut any_modified = false;
+        let mut any_empty = false;
+
+        for (index, contour) in shape.iter_mut().enumerate() {
+            if !self.clean_contour(contour) {
+                continue;
+            }
+            any_modified = true;
+
+            if !contour.is_empty() {
+                continue;
+            }
+
+            if index == 0 {
+                shape.clear();
+                return true;
+            }
+            any_empty = true;
+        }
+
+        if any_empty {
+            shape.retain(|contour| !contour.is_empty());
+        }
+
+        any_modified
+    }
+
+    /// Cleans all shapes in place, shapes with a degenerate outer contour are removed.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if any shape was modified.
+    /// - `false` if the shapes were not modified.
+    pub fn clean_shapes(&mut self, shapes: &mut IntShapes) -> bool {
+        let mut any_modified = false;
+        let mut any_empty = false;
+
+        for shape in shapes.iter_mut() {
+            any_modified |= self.clean_shape(shape);
+            any_empty |= shape.is_empty();
+        }
+
+        if any_empty {
+            shapes.retain(|shape| !shape.is_empty());
+        }
+
+        any_modified
+    }
+
+    /// Cleans all contours of the buffer in place, degenerate contours are removed.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the buffer was modified.
+    /// - `false` if the buffer was not modified.
+    #[inline]
+    pub fn clean_flat_contours(&mut self, buffer: &mut FlatContoursBuffer) -> bool {
+        rewrite_contours(&mut buffer.points, &mut buffer.ranges, |_, contour| {
+            self.edit(contour)
+        })
+    }
+
+    /// Cleans all contours of the buffer in place, degenerate holes are removed
+    /// and a shape is removed if its outer contour is degenerate.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the buffer was modified.
+    /// - `false` if the buffer was not modified.
+    #[inline]
+    pub fn clean_flat_shapes(&mut self, buffer: &mut FlatShapesBuffer) -> bool {
+        rewrite_shapes(
+            &mut buffer.points,
+            &mut buffer.contour_ranges,
+            &mut buffer.shape_ranges,
+            |_, _, contour| self.edit(contour),
+        )
+    }
+
+    /// Cleans a single contour of a flat buffer.
+    #[inline]
+    pub(crate) fn edit(&mut self, contour: &mut [IntPoint]) -> ContourEdit<IntPoint> {
+        match self.clean_slice(contour) {
+            0 => ContourEdit::Remove,
+            count if count == contour.len() => ContourEdit::Keep,
+            count => ContourEdit::Shrink(count),
+        }
+    }
+
+    /// Moves the kept points to the front of the slice.
+    ///
+    /// # Returns
+    ///
+    /// - The number of kept points, `0` if the contour is degenerate.
+    #[inline]
+    fn clean_slice(&mut self, contour: &mut [IntPoint]) -> usize {
+        let count = self.mark(contour);
+        if count == 0 || count == contour.len() {
+            return count;
+        }
+
+        let mut j = 0;
+        for i in 0..contour.len() {
+            if self.validated[i] {
+                contour[j] = contour[i];
+                j += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Marks the kept vertices in `validated`.
+    ///
+    /// # Returns
+    ///
+    /// - The number of kept points, `0` if the contour is degenerate.
+    fn mark(&mut self, contour: &[IntPoint]) -> usize {
+        let mut n = contour.len();
+
+        if n < 3 {
+            return 0;
+        }
+
+        self.validated.clear();
+        self.validated.resize(n, false);
+
+        self.nodes.clear();
+        self.nodes.reserve(n);
+
+        let mut prev = n - 1;
+        let last = n - 1;
+        for index in 0..last {
+            self.nodes.push(Node {
+                next: index + 1,
+                index,
+                prev,
+            });
+            prev = index;
+        }
+        self.nodes.push(Node {
+            next: 0,
+            index: last,
+            prev,
+        });
+
+        let mut node = self.nodes[0];
+        let mut i = 0;
+        while i < n {
+            if self.validated[node.index] {
+                node = self.nodes[node.next];
+                continue;
+            }
+
+            let p0 = contour[node.prev];
+            let p1 = contour[node.index];
+            let p2 = contour[node.next];
+
+            if orient(p0, p1, p2) == 0 {
+                n -= 1;
+                if n < 3 {
+                    return 0;
+                }
+
+                // remove node
+                self.nodes[node.prev].next = node.next;
+                self.nodes[node.next].prev = node.prev;
+
+                node = self.nodes[node.prev];
+
+                if self.validated[node.prev] {
+                    i -= 1;
+                    self.validated[node.prev] = false
+                }
+
+                if self.validated[node.next] {
+                    i -= 1;
+                    self.validated[node.next] = false
+                }
+
+                if self.validated[node.index] {
+                    i -= 1;
+                    self.validated[node.index] = false
+                }
+            } else {
+                self.validated[node.index] = true;
+                i += 1;
+                node = self.nodes[node.next];
+            }
+        }
+
+        // every kept vertex is validated and every removed one is not
+        n
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Node {
+    next: usize,
+    index: usize,
+    prev: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::int::clean::ContourCleaner;
+    use crate::int::simple::SimpleShapes;
+    use crate::{int_path, int_shapes};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_0() {
+        let mut cleaner = ContourCleaner::default();
+
+        // a duplicate, a collinear point and a spike
+        let contour = int_path![
+            [0, 0],
+            [0, 0],
+            [0, 5],
+            [0, 10],
+            [10, 10],
+            [15, 10],
+            [10, 10],
+            [10, 0],
+        ];
+        let expected = int_path![[0, 0], [0, 10], [10, 10], [10, 0],];
+
+        let mut output = Vec::new();
+        assert!(cleaner.clean_into(&contour, &mut output));
+        assert_eq!(output, expected);
+
+        assert!(!cleaner.clean_into(&int_path![[0, 0], [1, 1], [2, 2],], &mut output));
+        assert_eq!(output.len(), 4);
+
+        let mut in_place = contour.clone();
+        assert!(cleaner.clean_contour(&mut in_place));
+        assert_eq!(in_place, expected);
+        assert!(!cleaner.clean_contour(&mut in_place));
+
+        let mut line = int_path![[0, 0], [5, 0], [10, 0], [5, 0],];
+        assert!(cleaner.clean_contour(&mut line));
+        assert!(line.is_empty());
+    }
+
+    #[test]
+    fn test_1() {
+        let shapes = int_shapes![
+            [
+                [[0, 0], [0, 10], [10, 10], [10, 5], [10, 0],],
+                [[2, 2], [4, 2], [6, 2],],
+                [[5, 5], [6, 5], [6, 6], [6, 6],],
+            ],
+            [[[20, 0], [21, 1], [22, 2],], [[20, 0], [20, 1], [21, 0],],],
+            [[[30, 0], [30, 1], [31, 0],],],
+        ];
+
+        let mut cleaner = ContourCleaner::default();
+
+        let mut cleaned = shapes.clone();
+        assert!(cleaner.clean_shapes(&mut cleaned));
+        assert_eq!(cleaned, shapes.simplified());
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+        assert!(cleaner.clean_flat_shapes(&mut buffer));
+        assert_eq!(buffer.to_shapes(), cleaned);
+        assert_eq!(buffer.points.len(), 4 + 3 + 3);
+        assert!(!cleaner.clean_flat_shapes(&mut buffer));
+    }
+
+    #[test]
+    fn test_2() {
+        let mut cleaner = ContourCleaner::default();
+
+        // differences near 2^32 overflow a 64-bit cross product
+        let mut contour = int_path![
+            [i32::MIN, i32::MIN],
+            [0, i32::MIN],
+            [i32::MAX, i32::MIN],
+            [i32::MAX, i32::MAX],
+        ];
+        assert!(cleaner.clean_contour(&mut contour));
+        assert_eq!(
+            contour,
+            int_path![[i32::MIN, i32::MIN], [i32::MAX, i32::MIN], [i32::MAX, i32::MAX],]
+        );
+
+        let mut line = int_path![[i32::MIN, i32::MIN], [0, 0], [i32::MAX, i32::MAX], [-1, -1],];
+        assert!(cleaner.clean_contour(&mut line));
+        assert!(line.is_empty());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::int::shape::IntContour;
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::edit::{ContourEdit, rewrite_contours, rewrite_shapes};
+use crate::int::shape::{IntContour, IntShape, IntShapes};
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
 
 /// Trait for removing redundant points from a contour.
 pub trait DedupContour {
@@ -24,10 +28,249 @@ impl DedupContour for IntContour {
     }
 }
 
+/// A contour modified by a tolerance-based dedup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DedupChange {
+    /// The index of the shape in the original collection, `0` for a single shape or contour list.
+    pub shape: usize,
+    /// The index of the contour in the original shape or contour list.
+    pub contour: usize,
+    /// How the contour was modified.
+    pub kind: DedupKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupKind {
+    /// Near-duplicate points were merged.
+    Merged { removed_points: usize },
+    /// The contour has fewer than 3 points left and was removed.
+    /// A removed outer contour removes the whole shape, its holes are not reported.
+    Removed,
+}
+
+/// Trait for merging near-duplicate points.
+pub trait DedupPoints {
+    /// Merges consecutive points closer than or equal to `max_distance` to the last kept point,
+    /// a closing point near the first one is removed too.
+    /// Contours with fewer than 3 points left are removed.
+    /// A negative or not finite `max_distance` merges nothing.
+    ///
+    /// # Returns
+    ///
+    /// - A list of the modified contours, empty if nothing was changed.
+    fn dedup_points(&mut self, max_distance: f64) -> Vec<DedupChange>;
+}
+
+impl DedupPoints for IntContour {
+    fn dedup_points(&mut self, max_distance: f64) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let n = self.len();
+        let len = dedup_slice(self, IntNear::new(max_distance));
+        if let Some(kind) = DedupKind::new(n, len) {
+            changes.push(DedupChange {
+                shape: 0,
+                contour: 0,
+                kind,
+            });
+            self.truncate(if len < 3 { 0 } else { len });
+        }
+        changes
+    }
+}
+
+impl DedupPoints for IntShape {
+    fn dedup_points(&mut self, max_distance: f64) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        dedup_shape(self, 0, IntNear::new(max_distance), &mut changes);
+        changes
+    }
+}
+
+impl DedupPoints for IntShapes {
+    fn dedup_points(&mut self, max_distance: f64) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let is_near = IntNear::new(max_distance);
+        let mut any_empty = false;
+        for (index, shape) in self.iter_mut().enumerate() {
+            dedup_shape(shape, index, is_near, &mut changes);
+            any_empty |= shape.is_empty();
+        }
+
+        if any_empty {
+            self.retain(|shape| !shape.is_empty());
+        }
+
+        changes
+    }
+}
+
+impl DedupPoints for FlatContoursBuffer {
+    fn dedup_points(&mut self, max_distance: f64) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let is_near = IntNear::new(max_distance);
+        rewrite_contours(&mut self.points, &mut self.ranges, |contour, points| {
+            dedup_edit(points, 0, contour, is_near, &mut changes)
+        });
+        changes
+    }
+}
+
+impl DedupPoints for FlatShapesBuffer {
+    fn dedup_points(&mut self, max_distance: f64) -> Vec<DedupChange> {
+        let mut changes = Vec::new();
+        let is_near = IntNear::new(max_distance);
+        rewrite_shapes(
+            &mut self.points,
+            &mut self.contour_ranges,
+            &mut self.shape_ranges,
+            |shape, contour, points| dedup_edit(points, shape, contour, is_near, &mut changes),
+        );
+        changes
+    }
+}
+
+impl DedupKind {
+    #[inline]
+    pub(crate) fn new(old_len: usize, new_len: usize) -> Option<Self> {
+        if new_len < 3 {
+            Some(DedupKind::Removed)
+        } else if new_len < old_len {
+            Some(DedupKind::Merged {
+                removed_points: old_len - new_len,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// A check if two points can be merged.
+pub(crate) trait Near<P>: Copy {
+    fn is_near(&self, a: &P, b: &P) -> bool;
+}
+
+/// A distance check in `f64`, which is exact enough for any `i32` coordinates.
+#[derive(Clone, Copy)]
+struct IntNear {
+    sqr_distance: f64,
+}
+
+impl IntNear {
+    #[inline]
+    fn new(max_distance: f64) -> Self {
+        // a negative squared distance is never reached
+        let sqr_distance = if max_distance >= 0.0 && max_distance.is_finite() {
+            max_distance * max_distance
+        } else {
+            -1.0
+        };
+        Self { sqr_distance }
+    }
+}
+
+impl Near<IntPoint> for IntNear {
+    #[inline]
+    fn is_near(&self, a: &IntPoint, b: &IntPoint) -> bool {
+        let dx = (b.x as i64 - a.x as i64) as f64;
+        let dy = (b.y as i64 - a.y as i64) as f64;
+        dx * dx + dy * dy <= self.sqr_distance
+    }
+}
+
+/// Moves the kept points to the front of the slice.
+///
+/// # Returns
+///
+/// - The number of kept points.
+pub(crate) fn dedup_slice<P: Copy, N: Near<P>>(points: &mut [P], near: N) -> usize {
+    if points.is_empty() {
+        return 0;
+    }
+
+    let mut len = 1;
+    for i in 1..points.len() {
+        if !near.is_near(&points[len - 1], &points[i]) {
+            points[len] = points[i];
+            len += 1;
+        }
+    }
+
+    // the closing point
+    while len > 1 && near.is_near(&points[len - 1], &points[0]) {
+        len -= 1;
+    }
+
+    len
+}
+
+pub(crate) fn dedup_shape<P: Copy, N: Near<P>>(
+    shape: &mut Vec<Vec<P>>,
+    shape_index: usize,
+    near: N,
+    changes: &mut Vec<DedupChange>,
+) {
+    let mut any_removed = false;
+    for (index, contour) in shape.iter_mut().enumerate() {
+        let n = contour.len();
+        let len = dedup_slice(contour, near);
+        let kind = if let Some(kind) = DedupKind::new(n, len) {
+            kind
+        } else {
+            continue;
+        };
+
+        changes.push(DedupChange {
+            shape: shape_index,
+            contour: index,
+            kind,
+        });
+
+        if kind != DedupKind::Removed {
+            contour.truncate(len);
+            continue;
+        }
+
+        if index == 0 {
+            shape.clear();
+            return;
+        }
+
+        contour.clear();
+        any_removed = true;
+    }
+
+    if any_removed {
+        shape.retain(|contour| !contour.is_empty());
+    }
+}
+
+pub(crate) fn dedup_edit<P: Copy, N: Near<P>>(
+    points: &mut [P],
+    shape: usize,
+    contour: usize,
+    near: N,
+    changes: &mut Vec<DedupChange>,
+) -> ContourEdit<P> {
+    let len = dedup_slice(points, near);
+    let kind = if let Some(kind) = DedupKind::new(points.len(), len) {
+        kind
+    } else {
+        return ContourEdit::Keep;
+    };
+
+    changes.push(DedupChange { shape, contour, kind });
+    if kind == DedupKind::Removed {
+        ContourEdit::Remove
+    } else {
+        ContourEdit::Shrink(len)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::int::dedup::DedupContour;
-    use crate::int_path;
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::int::dedup::{DedupChange, DedupContour, DedupKind, DedupPoints};
+    use crate::{int_path, int_shapes};
 
     #[test]
     fn test_0() {
@@ -36,7 +279,7 @@ mod tests {
         let modified = contour.dedup_contour();
 
         assert_eq!(contour.len(), 2);
-        assert_eq!(modified, false);
+        assert!(!modified);
     }
 
     #[test]
@@ -46,7 +289,7 @@ mod tests {
         let modified = contour.dedup_contour();
 
         assert_eq!(contour.len(), 2);
-        assert_eq!(modified, true);
+        assert!(modified);
     }
 
     #[test]
@@ -56,6 +299,69 @@ mod tests {
         let modified = contour.dedup_contour();
 
         assert_eq!(contour.len(), 2);
-        assert_eq!(modified, true);
+        assert!(modified);
+    }
+
+    #[test]
+    fn test_3() {
+        let shapes = int_shapes![
+            [[[0, 0], [1, 0], [0, 1],], [[5, 5], [6, 5], [6, 6],],],
+            [
+                [[10, 0], [11, 0], [20, 0], [20, 10], [10, 10], [10, 1],],
+                [[12, 2], [14, 2], [14, 4], [12, 4],],
+            ],
+        ];
+
+        let mut cleaned = shapes.clone();
+        let changes = cleaned.dedup_points(1.5);
+        assert_eq!(
+            changes,
+            [
+                DedupChange {
+                    shape: 0,
+                    contour: 0,
+                    kind: DedupKind::Removed
+                },
+                DedupChange {
+                    shape: 1,
+                    contour: 0,
+                    kind: DedupKind::Merged { removed_points: 2 }
+                },
+            ]
+        );
+        assert_eq!(
+            cleaned,
+            int_shapes![[
+                [[10, 0], [20, 0], [20, 10], [10, 10],],
+                [[12, 2], [14, 2], [14, 4], [12, 4],],
+            ],]
+        );
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+        assert_eq!(buffer.dedup_points(1.5), changes);
+        assert_eq!(buffer.to_shapes(), cleaned);
+
+        let mut contours = FlatContoursBuffer::default();
+        contours.set_with_shapes(&shapes);
+        assert_eq!(contours.dedup_points(1.5).len(), 3);
+        assert_eq!(contours.ranges.len(), 2);
+        assert!(contours.dedup_points(1.5).is_empty());
+    }
+
+    #[test]
+    fn test_4() {
+        let contour = int_path![[0, 0], [0, 0], [1, 0], [10, 0], [10, 10],];
+        for max_distance in [-1.0, -0.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let mut modified = contour.clone();
+            let changes = modified.dedup_points(max_distance);
+            if max_distance == 0.0 {
+                // an exact duplicate is merged
+                assert_eq!(changes.len(), 1);
+            } else {
+                assert!(changes.is_empty());
+                assert_eq!(modified, contour);
+            }
+        }
     }
 }
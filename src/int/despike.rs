@@ -1,8 +1,24 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::flat::edit::{ContourEdit, rewrite_contours, rewrite_shapes};
+use crate::int::exact::{dot, orient};
 use crate::int::shape::{IntContour, IntShape, IntShapes};
 use alloc::vec;
 use alloc::vec::Vec;
+use i_float::float::number::FloatNumber;
 use i_float::int::point::IntPoint;
 
+/// A threshold to also remove near-spikes, which are not perfectly reversed edges.
+///
+/// Perfect spikes are always removed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpikeThreshold {
+    /// Removes vertices where the angle between the two adjacent edges is below `min_angle` radians.
+    Angle { min_angle: f64 },
+    /// Removes vertices where the end of the shorter adjacent edge is closer
+    /// than `min_width` to the line of the longer one.
+    Width { min_width: f64 },
+}
+
 /// A trait for removing spike artifacts from polygon contours.
 pub trait DeSpike {
     /// Removes spikes from the contour in-place.
@@ -12,6 +28,14 @@ pub trait DeSpike {
     /// - `true` if spikes were found and removed.
     /// - `false` if the contour was already clean.
     fn remove_spikes(&mut self) -> bool;
+
+    /// Removes spikes and near-spikes from the contour in-place.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if spikes were found and removed.
+    /// - `false` if the contour was already clean.
+    fn remove_spikes_with(&mut self, threshold: SpikeThreshold) -> bool;
 }
 
 pub trait DeSpikeContour {
@@ -33,6 +57,14 @@ pub trait DeSpikeContour {
     /// - `Some(IntContour)` if a valid, despiked contour can be produced.
     /// - `None` if the contour is degenerate after spike removal.
     fn despiked_contour(&self) -> Option<IntContour>;
+
+    /// Returns a copy of the contour with spikes and near-spikes removed.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(IntContour)` if a valid, despiked contour can be produced.
+    /// - `None` if the contour is degenerate after spike removal.
+    fn despiked_contour_with(&self, threshold: SpikeThreshold) -> Option<IntContour>;
 }
 
 pub trait DeSpikeShape {
@@ -54,6 +86,14 @@ pub trait DeSpikeShape {
     /// - `Some(IntShape)` containing the simplified shape if simplification is possible.
     /// - `None` if the shape is degenerate or empty.
     fn despiked_shape(&self) -> Option<IntShape>;
+
+    /// Returns a copy of the shape with spikes and near-spikes removed.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(IntShape)` containing the despiked shape.
+    /// - `None` if the outer contour is degenerate.
+    fn despiked_shape_with(&self, threshold: SpikeThreshold) -> Option<IntShape>;
 }
 
 pub trait DeSpikeShapes {
@@ -74,6 +114,13 @@ pub trait DeSpikeShapes {
     ///
     /// - `IntShapes` the simplified shapes.
     fn despiked_shapes(&self) -> IntShapes;
+
+    /// Returns a copy of the collection with spikes and near-spikes removed.
+    ///
+    /// # Returns
+    ///
+    /// - `IntShapes` the despiked shapes.
+    fn despiked_shapes_with(&self, threshold: SpikeThreshold) -> IntShapes;
 }
 
 impl DeSpike for IntContour {
@@ -88,9 +135,36 @@ impl DeSpike for IntContour {
         }
         true
     }
+
+    fn remove_spikes_with(&mut self, threshold: SpikeThreshold) -> bool {
+        let n = self.len();
+        if let Some(contour) = self.despiked_contour_with(threshold) {
+            *self = contour;
+        } else {
+            self.clear()
+        }
+        self.len() < n
+    }
 }
 
 impl DeSpikeContour for IntContour {
+    #[inline]
+    fn has_no_spikes(&self) -> bool {
+        self.as_slice().has_no_spikes()
+    }
+
+    #[inline]
+    fn despiked_contour(&self) -> Option<IntContour> {
+        self.as_slice().despiked_contour()
+    }
+
+    #[inline]
+    fn despiked_contour_with(&self, threshold: SpikeThreshold) -> Option<IntContour> {
+        self.as_slice().despiked_contour_with(threshold)
+    }
+}
+
+impl DeSpikeContour for [IntPoint] {
     fn has_no_spikes(&self) -> bool {
         let count = self.len();
 
@@ -118,99 +192,104 @@ impl DeSpikeContour for IntContour {
         true
     }
 
+    #[inline]
     fn despiked_contour(&self) -> Option<IntContour> {
-        if self.len() < 3 {
-            return None;
-        }
-
-        let mut n = self.len();
-        let mut nodes: Vec<Node> = vec![
-            Node {
-                next: 0,
-                index: 0,
-                prev: 0
-            };
-            n
-        ];
-        let mut validated: Vec<bool> = vec![false; n];
-
-        let mut i0 = n - 2;
-        let mut i1 = n - 1;
-        for i2 in 0..n {
-            nodes[i1] = Node {
-                next: i2,
-                index: i1,
-                prev: i0,
-            };
-            i0 = i1;
-            i1 = i2;
-        }
+        despike(self, SpikeFilter::Exact)
+    }
 
-        let mut first: usize = 0;
-        let mut node = nodes[first];
-        let mut i = 0;
-        while i < n {
-            if validated[node.index] {
-                node = nodes[node.next];
-                continue;
-            }
+    #[inline]
+    fn despiked_contour_with(&self, threshold: SpikeThreshold) -> Option<IntContour> {
+        despike(self, SpikeFilter::new(threshold))
+    }
+}
 
-            let p0 = self[node.prev];
-            let p1 = self[node.index];
-            let p2 = self[node.next];
+fn despike(contour: &[IntPoint], filter: SpikeFilter) -> Option<IntContour> {
+    if contour.len() < 3 {
+        return None;
+    }
 
-            let v10 = p1.subtract(p0);
-            let v21 = p2.subtract(p1);
-            let cross = v10.cross_product(v21);
-            let dot = v10.dot_product(v21);
+    let mut n = contour.len();
+    let mut nodes: Vec<Node> = vec![
+        Node {
+            next: 0,
+            index: 0,
+            prev: 0
+        };
+        n
+    ];
+    let mut validated: Vec<bool> = vec![false; n];
+
+    let mut i0 = n - 2;
+    let mut i1 = n - 1;
+    for i2 in 0..n {
+        nodes[i1] = Node {
+            next: i2,
+            index: i1,
+            prev: i0,
+        };
+        i0 = i1;
+        i1 = i2;
+    }
 
-            if cross == 0 && dot < 0 {
-                n -= 1;
-                if n < 3 {
-                    return None;
-                }
+    let mut first: usize = 0;
+    let mut node = nodes[first];
+    let mut i = 0;
+    while i < n {
+        if validated[node.index] {
+            node = nodes[node.next];
+            continue;
+        }
 
-                // remove node
-                nodes[node.prev].next = node.next;
-                nodes[node.next].prev = node.prev;
+        let p0 = contour[node.prev];
+        let p1 = contour[node.index];
+        let p2 = contour[node.next];
 
-                if node.index == first {
-                    first = node.next
-                }
+        if filter.is_spike(p0, p1, p2) {
+            n -= 1;
+            if n < 3 {
+                return None;
+            }
 
-                node = nodes[node.prev];
+            // remove node
+            nodes[node.prev].next = node.next;
+            nodes[node.next].prev = node.prev;
 
-                if validated[node.prev] {
-                    i -= 1;
-                    validated[node.prev] = false
-                }
+            if node.index == first {
+                first = node.next
+            }
 
-                if validated[node.next] {
-                    i -= 1;
-                    validated[node.next] = false
-                }
+            node = nodes[node.prev];
 
-                if validated[node.index] {
-                    i -= 1;
-                    validated[node.index] = false
-                }
-            } else {
-                validated[node.index] = true;
-                i += 1;
-                node = nodes[node.next];
+            if validated[node.prev] {
+                i -= 1;
+                validated[node.prev] = false
             }
-        }
 
-        let mut buffer = vec![IntPoint::ZERO; n];
-        node = nodes[first];
+            if validated[node.next] {
+                i -= 1;
+                validated[node.next] = false
+            }
 
-        for item in buffer.iter_mut().take(n) {
-            *item = self[node.index];
+            if validated[node.index] {
+                i -= 1;
+                validated[node.index] = false
+            }
+        } else {
+            validated[node.index] = true;
+            i += 1;
             node = nodes[node.next];
         }
+    }
+
+    let mut buffer = vec![IntPoint::ZERO; n];
+    node = nodes[first];
 
-        Some(buffer)
+    for item in buffer.iter_mut().take(n) {
+        *item = contour[node.index];
+        node = nodes[node.next];
     }
+
+    Some(buffer)
 }
 
 impl DeSpike for IntShape {
@@ -242,6 +321,35 @@ impl DeSpike for IntShape {
 
         any_simplified
     }
+
+    fn remove_spikes_with(&mut self, threshold: SpikeThreshold) -> bool {
+        let mut any_simplified = false;
+        let mut any_empty = false;
+
+        for (index, contour) in self.iter_mut().enumerate() {
+            if !contour.remove_spikes_with(threshold) {
+                continue;
+            }
+            any_simplified = true;
+
+            if !contour.is_empty() {
+                continue;
+            }
+
+            if index == 0 {
+                // early out main contour is empty
+                self.clear();
+                return true;
+            }
+            any_empty = true;
+        }
+
+        if any_empty {
+            self.retain(|contour| !contour.is_empty());
+        }
+
+        any_simplified
+    }
 }
 
 impl DeSpikeShape for IntShape {
@@ -268,6 +376,19 @@ impl DeSpikeShape for IntShape {
 
         Some(contours)
     }
+
+    fn despiked_shape_with(&self, threshold: SpikeThreshold) -> Option<IntShape> {
+        let mut contours = Vec::with_capacity(self.len());
+        for (i, contour) in self.iter().enumerate() {
+            if let Some(simple) = contour.despiked_contour_with(threshold) {
+                contours.push(simple);
+            } else if i == 0 {
+                return None;
+            }
+        }
+
+        Some(contours)
+    }
 }
 
 impl DeSpike for IntShapes {
@@ -294,6 +415,22 @@ impl DeSpike for IntShapes {
 
         any_simplified
     }
+
+    fn remove_spikes_with(&mut self, threshold: SpikeThreshold) -> bool {
+        let mut any_simplified = false;
+        let mut any_empty = false;
+
+        for shape in self.iter_mut() {
+            any_simplified |= shape.remove_spikes_with(threshold);
+            any_empty |= shape.is_empty();
+        }
+
+        if any_empty {
+            self.retain(|contour| !contour.is_empty());
+        }
+
+        any_simplified
+    }
 }
 
 impl DeSpikeShapes for IntShapes {
@@ -318,6 +455,131 @@ impl DeSpikeShapes for IntShapes {
 
         shapes
     }
+
+    fn despiked_shapes_with(&self, threshold: SpikeThreshold) -> IntShapes {
+        self.iter()
+            .filter_map(|shape| shape.despiked_shape_with(threshold))
+            .collect()
+    }
+}
+
+impl DeSpike for FlatContoursBuffer {
+    /// Removes spikes from every contour in place, degenerate contours are removed.
+    #[inline]
+    fn remove_spikes(&mut self) -> bool {
+        rewrite_contours(&mut self.points, &mut self.ranges, |_, contour| {
+            despike_edit(contour, None)
+        })
+    }
+
+    #[inline]
+    fn remove_spikes_with(&mut self, threshold: SpikeThreshold) -> bool {
+        rewrite_contours(&mut self.points, &mut self.ranges, |_, contour| {
+            despike_edit(contour, Some(threshold))
+        })
+    }
+}
+
+impl DeSpike for FlatShapesBuffer {
+    /// Removes spikes from every contour in place, degenerate holes are removed
+    /// and a shape is removed if its outer contour is degenerate.
+    #[inline]
+    fn remove_spikes(&mut self) -> bool {
+        rewrite_shapes(
+            &mut self.points,
+            &mut self.contour_ranges,
+            &mut self.shape_ranges,
+            |_, _, contour| despike_edit(contour, None),
+        )
+    }
+
+    #[inline]
+    fn remove_spikes_with(&mut self, threshold: SpikeThreshold) -> bool {
+        rewrite_shapes(
+            &mut self.points,
+            &mut self.contour_ranges,
+            &mut self.shape_ranges,
+            |_, _, contour| despike_edit(contour, Some(threshold)),
+        )
+    }
+}
+
+/// Despikes a single contour of a flat buffer, only perfect spikes are removed without `threshold`.
+pub(crate) fn despike_edit(contour: &[IntPoint], threshold: Option<SpikeThreshold>) -> ContourEdit<IntPoint> {
+    let filter = if let Some(threshold) = threshold {
+        SpikeFilter::new(threshold)
+    } else if contour.has_no_spikes() {
+        return ContourEdit::Keep;
+    } else {
+        SpikeFilter::Exact
+    };
+
+    match despike(contour, filter) {
+        Some(despiked) if despiked.len() == contour.len() => ContourEdit::Keep,
+        Some(despiked) => ContourEdit::Replace(despiked),
+        None => ContourEdit::Remove,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SpikeFilter {
+    Exact,
+    Angle { min_cos: f64 },
+    Width { sqr_width: f64 },
+}
+
+impl SpikeFilter {
+    #[inline]
+    fn new(threshold: SpikeThreshold) -> Self {
+        match threshold {
+            SpikeThreshold::Angle { min_angle } => Self::Angle {
+                min_cos: FloatNumber::cos(min_angle),
+            },
+            SpikeThreshold::Width { min_width } => Self::Width {
+                sqr_width: min_width * min_width,
+            },
+        }
+    }
+
+    /// Checks the vertex `p1` between its neighbors `p0` and `p2`.
+    #[inline]
+    fn is_spike(&self, p0: IntPoint, p1: IntPoint, p2: IntPoint) -> bool {
+        let cross = orient(p1, p0, p2);
+        let dot = dot(p1, p0, p2);
+        if cross == 0 {
+            // the edges are reversed
+            return dot > 0;
+        }
+
+        match *self {
+            SpikeFilter::Exact => false,
+            SpikeFilter::Angle { min_cos } => {
+                // cos(angle) > min_cos
+                let dot = dot as f64;
+                if dot <= 0.0 && min_cos >= 0.0 {
+                    return false;
+                }
+                let len = FloatNumber::sqrt(sqr_length(p1, p0) * sqr_length(p1, p2));
+                dot > min_cos * len
+            }
+            SpikeFilter::Width { sqr_width } => {
+                if dot <= 0 {
+                    return false;
+                }
+                // the distance from the end of the shorter edge to the longer one is |cross| / |longer|
+                let sqr_len = sqr_length(p1, p0).max(sqr_length(p1, p2));
+                let cross = cross as f64;
+                cross * cross < sqr_width * sqr_len
+            }
+        }
+    }
+}
+
+#[inline]
+fn sqr_length(a: IntPoint, b: IntPoint) -> f64 {
+    let dx = (b.x as i64 - a.x as i64) as f64;
+    let dy = (b.y as i64 - a.y as i64) as f64;
+    dx * dx + dy * dy
 }
 
 #[derive(Clone, Copy)]
@@ -329,8 +591,9 @@ struct Node {
 
 #[cfg(test)]
 mod tests {
-    use crate::int::despike::DeSpike;
-    use crate::int_path;
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::int::despike::{DeSpike, DeSpikeContour, DeSpikeShapes, SpikeThreshold};
+    use crate::{int_path, int_shapes};
 
     #[test]
     fn test_0() {
@@ -339,7 +602,7 @@ mod tests {
         let modified = contour.remove_spikes();
 
         assert_eq!(contour.len(), 4);
-        assert_eq!(modified, false);
+        assert!(!modified);
     }
 
     #[test]
@@ -349,7 +612,7 @@ mod tests {
         let modified = contour.remove_spikes();
 
         assert_eq!(contour.len(), 4);
-        assert_eq!(modified, true);
+        assert!(modified);
     }
 
     #[test]
@@ -359,7 +622,7 @@ mod tests {
         let modified = contour.remove_spikes();
 
         assert_eq!(contour.len(), 4);
-        assert_eq!(modified, true);
+        assert!(modified);
     }
 
     #[test]
@@ -369,7 +632,7 @@ mod tests {
         let modified = contour.remove_spikes();
 
         assert_eq!(contour.len(), 5);
-        assert_eq!(modified, true);
+        assert!(modified);
     }
 
     #[test]
@@ -379,7 +642,7 @@ mod tests {
         let modified = contour.remove_spikes();
 
         assert_eq!(contour.len(), 5);
-        assert_eq!(modified, true);
+        assert!(modified);
     }
 
     #[test]
@@ -398,6 +661,65 @@ mod tests {
         let modified = contour.remove_spikes();
 
         assert_eq!(contour.len(), 8);
-        assert_eq!(modified, false);
+        assert!(!modified);
+    }
+
+    #[test]
+    fn test_6() {
+        // the spike at [10, 5] is off by one unit
+        let contour = int_path![[0, 0], [5, 0], [10, 5], [5, 1], [5, 10], [0, 10],];
+
+        let mut exact = contour.clone();
+        assert!(!exact.remove_spikes());
+
+        let mut angle = contour.clone();
+        assert!(angle.remove_spikes_with(SpikeThreshold::Angle { min_angle: 0.2 }));
+        assert_eq!(angle, int_path![[0, 0], [5, 0], [5, 1], [5, 10], [0, 10],]);
+
+        let mut width = contour.clone();
+        assert!(width.remove_spikes_with(SpikeThreshold::Width { min_width: 1.0 }));
+        assert_eq!(width, angle);
+
+        // thresholds below the spike size keep it
+        let mut narrow = contour.clone();
+        assert!(!narrow.remove_spikes_with(SpikeThreshold::Width { min_width: 0.5 }));
+        assert!(!narrow.remove_spikes_with(SpikeThreshold::Angle { min_angle: 0.1 }));
+    }
+
+    #[test]
+    fn test_7() {
+        let shapes = int_shapes![
+            [[[0, 0], [10, 0], [10, 10], [0, 10],], [[2, 2], [2, 8], [3, 2],],],
+            [[[20, 0], [30, 0], [20, 1],],],
+        ];
+
+        let result = shapes.despiked_shapes_with(SpikeThreshold::Angle { min_angle: 0.2 });
+        assert_eq!(result, int_shapes![[[[0, 0], [10, 0], [10, 10], [0, 10],],],]);
+    }
+
+    #[test]
+    fn test_8() {
+        let mut shapes = int_shapes![
+            [[[0, 0], [0, 2], [1, 2], [3, 2], [4, 2], [2, 2], [2, 0],],],
+            [[[10, 0], [10, 2], [10, 1],],],
+            [
+                [[20, 0], [20, 9], [29, 9], [29, 0],],
+                [[22, 2], [24, 2], [26, 2],],
+                [[25, 5], [25, 6], [26, 6], [26, 5],],
+            ],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        assert!(buffer.remove_spikes());
+        assert!(shapes.remove_spikes());
+        assert_eq!(buffer.to_shapes(), shapes);
+        assert_eq!(buffer.shape_ranges.len(), 2);
+        assert!(!buffer.remove_spikes());
+
+        let contour = &buffer.to_shapes()[0][0];
+        assert!(contour.has_no_spikes());
+        assert!(contour.as_slice().has_no_spikes());
     }
 }
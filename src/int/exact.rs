@@ -0,0 +1,32 @@
+use i_float::int::point::IntPoint;
+
+/// The exact doubled signed area of the triangle `a`, `b`, `c`.
+/// - Returns: A positive value if `a`, `b`, `c` are counter-clockwise ordered, negative if they are clockwise ordered and zero if they are collinear.
+#[inline(always)]
+pub(crate) fn orient(a: IntPoint, b: IntPoint, c: IntPoint) -> i128 {
+    let abx = b.x as i64 - a.x as i64;
+    let aby = b.y as i64 - a.y as i64;
+    let acx = c.x as i64 - a.x as i64;
+    let acy = c.y as i64 - a.y as i64;
+    abx as i128 * acy as i128 - aby as i128 * acx as i128
+}
+
+/// Checks if `p` lies on the closed segment `a`, `b`.
+#[inline]
+pub(crate) fn is_on_segment(p: IntPoint, a: IntPoint, b: IntPoint) -> bool {
+    a.x.min(b.x) <= p.x
+        && p.x <= a.x.max(b.x)
+        && a.y.min(b.y) <= p.y
+        && p.y <= a.y.max(b.y)
+        && orient(a, b, p) == 0
+}
+
+/// The exact dot product of the vectors `a - o` and `b - o`.
+#[inline(always)]
+pub(crate) fn dot(o: IntPoint, a: IntPoint, b: IntPoint) -> i128 {
+    let oax = a.x as i64 - o.x as i64;
+    let oay = a.y as i64 - o.y as i64;
+    let obx = b.x as i64 - o.x as i64;
+    let oby = b.y as i64 - o.y as i64;
+    oax as i128 * obx as i128 + oay as i128 * oby as i128
+}
@@ -0,0 +1,188 @@
+use crate::base::direction::ContourDirection;
+use crate::flat::buffer::FlatContoursBuffer;
+use crate::int::exact::orient;
+use crate::int::shape::{IntContour, IntShape};
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+
+pub trait ConvexHull {
+    /// Builds the convex hull of all points with Andrew's monotone chain algorithm.
+    ///
+    /// - `direction`: The direction of the resulting contour.
+    /// - `keep_collinear`: If `true`, points lying on the hull edges are kept.
+    ///
+    /// # Returns
+    ///
+    /// - The hull contour, it has fewer than 3 points if all points are collinear:
+    ///   the two extreme points or a single point.
+    fn convex_hull(&self, direction: ContourDirection, keep_collinear: bool) -> IntContour;
+}
+
+impl ConvexHull for [IntPoint] {
+    #[inline]
+    fn convex_hull(&self, direction: ContourDirection, keep_collinear: bool) -> IntContour {
+        hull(self.to_vec(), direction, keep_collinear)
+    }
+}
+
+impl ConvexHull for [IntContour] {
+    #[inline]
+    fn convex_hull(&self, direction: ContourDirection, keep_collinear: bool) -> IntContour {
+        let points = self.iter().flatten().copied().collect();
+        hull(points, direction, keep_collinear)
+    }
+}
+
+impl ConvexHull for [IntShape] {
+    #[inline]
+    fn convex_hull(&self, direction: ContourDirection, keep_collinear: bool) -> IntContour {
+        let points = self.iter().flatten().flatten().copied().collect();
+        hull(points, direction, keep_collinear)
+    }
+}
+
+impl ConvexHull for FlatContoursBuffer {
+    #[inline]
+    fn convex_hull(&self, direction: ContourDirection, keep_collinear: bool) -> IntContour {
+        let points = self
+            .ranges
+            .iter()
+            .flat_map(|range| self.points[range.clone()].iter())
+            .copied()
+            .collect();
+        hull(points, direction, keep_collinear)
+    }
+}
+
+pub(crate) fn hull(
+    mut points: Vec<IntPoint>,
+    direction: ContourDirection,
+    keep_collinear: bool,
+) -> IntContour {
+    points.sort_unstable_by(|a, b| a.x.cmp(&b.x).then(a.y.cmp(&b.y)));
+    points.dedup();
+
+    let n = points.len();
+    if n < 3 {
+        return points;
+    }
+
+    // a left turn is required, collinear points are popped unless they are kept
+    let is_popped = |turn: i128| if keep_collinear { turn < 0 } else { turn <= 0 };
+
+    let mut result: Vec<IntPoint> = Vec::with_capacity(n + 1);
+
+    // lower chain
+    for &p in points.iter() {
+        while result.len() >= 2 && is_popped(orient(result[result.len() - 2], result[result.len() - 1], p)) {
+            result.pop();
+        }
+        result.push(p);
+    }
+
+    // upper chain
+    let lower_len = result.len() + 1;
+    for &p in points.iter().rev().skip(1) {
+        while result.len() >= lower_len
+            && is_popped(orient(result[result.len() - 2], result[result.len() - 1], p))
+        {
+            result.pop();
+        }
+        result.push(p);
+    }
+
+    // the last point is the first one
+    result.pop();
+
+    if result.len() > n {
+        // only possible if all points are collinear and were kept by both chains
+        return alloc::vec![points[0], points[n - 1]];
+    }
+
+    if result.len() < 3 {
+        return result;
+    }
+
+    // the chains are counter-clockwise
+    if direction == ContourDirection::Clockwise {
+        result.reverse();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::direction::ContourDirection;
+    use crate::flat::buffer::FlatContoursBuffer;
+    use crate::int::area::ExactArea;
+    use crate::int::hull::ConvexHull;
+    use crate::int::location::{ClassifyPoint, PointLocation};
+    use crate::{int_path, int_shapes};
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use rand::RngExt;
+
+    #[test]
+    fn test_0() {
+        let points = int_path![[0, 0], [2, 0], [4, 0], [4, 4], [2, 2], [0, 4], [2, 4], [4, 4],];
+
+        let hull = points.convex_hull(ContourDirection::CounterClockwise, false);
+        assert_eq!(hull, int_path![[0, 0], [4, 0], [4, 4], [0, 4],]);
+        assert!(hull.exact_area_two() < 0);
+
+        let hull = points.convex_hull(ContourDirection::Clockwise, true);
+        assert_eq!(hull, int_path![[0, 4], [2, 4], [4, 4], [4, 0], [2, 0], [0, 0],]);
+        assert!(hull.exact_area_two() > 0);
+    }
+
+    #[test]
+    fn test_1() {
+        let line = int_path![[2, 2], [0, 0], [1, 1], [3, 3],];
+        assert_eq!(
+            line.convex_hull(ContourDirection::CounterClockwise, true),
+            int_path![[0, 0], [3, 3],]
+        );
+        assert_eq!(
+            line.convex_hull(ContourDirection::CounterClockwise, false),
+            int_path![[0, 0], [3, 3],]
+        );
+        assert_eq!(
+            int_path![[1, 1], [1, 1],].convex_hull(ContourDirection::CounterClockwise, false),
+            int_path![[1, 1],]
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        let shapes = int_shapes![[[[0, 0], [1, 0], [0, 1],],], [[[5, 5], [6, 5], [5, 6],],],];
+        let mut buffer = FlatContoursBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let hull = shapes.convex_hull(ContourDirection::CounterClockwise, false);
+        assert_eq!(hull, int_path![[0, 0], [1, 0], [6, 5], [5, 6], [0, 1],]);
+        assert_eq!(
+            buffer.convex_hull(ContourDirection::CounterClockwise, false),
+            hull
+        );
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = rand::rng();
+        for _ in 0..1000 {
+            let n = rng.random_range(3..30);
+            let points: Vec<IntPoint> = (0..n)
+                .map(|_| IntPoint::new(rng.random_range(-10..10), rng.random_range(-10..10)))
+                .collect();
+
+            let hull = points.convex_hull(ContourDirection::CounterClockwise, false);
+            if hull.len() < 3 {
+                continue;
+            }
+            for &p in points.iter() {
+                assert_ne!(hull.classify_point(p), PointLocation::Outside);
+            }
+        }
+    }
+}
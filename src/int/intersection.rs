@@ -0,0 +1,525 @@
+use crate::int::exact::{dot, is_on_segment, orient};
+use crate::int::shape::IntContour;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Bound::{Excluded, Unbounded};
+use i_float::int::point::IntPoint;
+
+/// A pair of contour edges that cross or touch each other.
+///
+/// Edge `i` connects points `i` and `(i + 1) % n`, `a` is always less than `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgePair {
+    pub a: usize,
+    pub b: usize,
+}
+
+/// A reference to an edge of a shape contour.
+///
+/// Edge `edge` connects points `edge` and `(edge + 1) % n` of the contour `contour`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EdgeRef {
+    pub contour: usize,
+    pub edge: usize,
+}
+
+pub trait SelfIntersection {
+    /// Searches for two edges of the contour that cross or touch each other.
+    ///
+    /// Adjacent edges may share only their common vertex, any other common point
+    /// (including a fold back along the same line) is reported. Non-adjacent edges must not
+    /// share any point, so a figure-eight or a contour touching itself at a vertex is rejected.
+    ///
+    /// The search is a sweep line over the edges and runs in `O(n log n)`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(EdgePair)` with the first offending pair found by the sweep.
+    /// - `None` if the contour does not intersect itself.
+    fn find_self_intersection(&self) -> Option<EdgePair>;
+
+    /// Checks whether any two edges of the contour cross or touch each other.
+    fn has_self_intersection(&self) -> bool;
+}
+
+pub trait ShapeIntersection {
+    /// Searches for two edges of the shape contours that cross or touch each other.
+    ///
+    /// Edges of the same contour follow the rules of `SelfIntersection`,
+    /// edges of different contours must not share any point.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((EdgeRef, EdgeRef))` with the first offending pair found by the sweep, ordered.
+    /// - `None` if the contours are simple and do not intersect each other.
+    fn find_intersection(&self) -> Option<(EdgeRef, EdgeRef)>;
+}
+
+impl SelfIntersection for [IntPoint] {
+    #[inline]
+    fn find_self_intersection(&self) -> Option<EdgePair> {
+        let (e0, e1) = EdgeSweep::default().find(&[self])?;
+        Some(EdgePair {
+            a: e0.edge,
+            b: e1.edge,
+        })
+    }
+
+    #[inline]
+    fn has_self_intersection(&self) -> bool {
+        self.find_self_intersection().is_some()
+    }
+}
+
+impl ShapeIntersection for [IntContour] {
+    #[inline]
+    fn find_intersection(&self) -> Option<(EdgeRef, EdgeRef)> {
+        let contours: Vec<&[IntPoint]> = self.iter().map(|contour| contour.as_slice()).collect();
+        EdgeSweep::default().find(&contours)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Segment {
+    // a < b
+    a: IntPoint,
+    b: IntPoint,
+    contour: usize,
+    edge: usize,
+    count: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Vertex {
+    point: IntPoint,
+    contour: usize,
+    index: usize,
+    count: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Event {
+    point: IntPoint,
+    is_start: bool,
+    segment: usize,
+}
+
+/// Active segment key ordered bottom to top along the sweep line.
+#[derive(Clone, Copy)]
+struct ActiveKey {
+    a: IntPoint,
+    b: IntPoint,
+    id: usize,
+}
+
+/// A reusable Shamos-Hoey sweep detecting whether any two edges of a contour set intersect.
+#[derive(Default)]
+pub(crate) struct EdgeSweep {
+    segments: Vec<Segment>,
+    vertices: Vec<Vertex>,
+    events: Vec<Event>,
+}
+
+impl EdgeSweep {
+    pub(crate) fn find(&mut self, contours: &[&[IntPoint]]) -> Option<(EdgeRef, EdgeRef)> {
+        if let Some(pair) = self.find_shared_vertex(contours) {
+            return Some(pair);
+        }
+
+        self.fill_segments(contours);
+
+        let mut active: BTreeSet<ActiveKey> = BTreeSet::new();
+        for i in 0..self.events.len() {
+            let event = self.events[i];
+            let s = self.segments[event.segment];
+            let key = ActiveKey {
+                a: s.a,
+                b: s.b,
+                id: event.segment,
+            };
+
+            let below = active.range(..key).next_back().copied();
+            let above = active.range((Excluded(key), Unbounded)).next().copied();
+
+            if event.is_start {
+                for other in [below, above].into_iter().flatten() {
+                    if self.is_intersect(key.id, other.id) {
+                        return Some(self.edge_pair(key.id, other.id));
+                    }
+                }
+                active.insert(key);
+            } else {
+                active.remove(&key);
+                if let (Some(below), Some(above)) = (below, above)
+                    && self.is_intersect(below.id, above.id)
+                {
+                    return Some(self.edge_pair(below.id, above.id));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Non-adjacent edges sharing a vertex are found by sorting all vertices.
+    fn find_shared_vertex(&mut self, contours: &[&[IntPoint]]) -> Option<(EdgeRef, EdgeRef)> {
+        self.vertices.clear();
+        for (contour, points) in contours.iter().enumerate() {
+            let count = points.len();
+            for (index, &point) in points.iter().enumerate() {
+                self.vertices.push(Vertex {
+                    point,
+                    contour,
+                    index,
+                    count,
+                });
+            }
+        }
+
+        self.vertices
+            .sort_unstable_by(|v0, v1| v0.point.cmp(&v1.point).then(v0.contour.cmp(&v1.contour)));
+
+        let mut best: Option<(EdgeRef, EdgeRef)> = None;
+        let mut i = 0;
+        while i < self.vertices.len() {
+            let mut j = i + 1;
+            while j < self.vertices.len() && self.vertices[j].point == self.vertices[i].point {
+                j += 1;
+            }
+
+            for k0 in i..j {
+                for k1 in k0 + 1..j {
+                    let v0 = self.vertices[k0];
+                    let v1 = self.vertices[k1];
+                    for e0 in v0.incident_edges() {
+                        for e1 in v1.incident_edges() {
+                            if e0 == e1 || is_adjacent(e0, e1, v0.count) {
+                                continue;
+                            }
+                            let pair = if e0 < e1 { (e0, e1) } else { (e1, e0) };
+                            if best.is_none_or(|b| pair < b) {
+                                best = Some(pair);
+                            }
+                        }
+                    }
+                }
+            }
+
+            if best.is_some() {
+                return best;
+            }
+
+            i = j;
+        }
+
+        None
+    }
+
+    fn fill_segments(&mut self, contours: &[&[IntPoint]]) {
+        self.segments.clear();
+        self.events.clear();
+
+        for (contour, points) in contours.iter().enumerate() {
+            let count = points.len();
+            for (edge, &p0) in points.iter().enumerate() {
+                let p1 = points[(edge + 1) % count];
+                if p0 == p1 {
+                    continue;
+                }
+                let (a, b) = if p0 < p1 { (p0, p1) } else { (p1, p0) };
+                let segment = self.segments.len();
+                self.segments.push(Segment {
+                    a,
+                    b,
+                    contour,
+                    edge,
+                    count,
+                });
+                self.events.push(Event {
+                    point: a,
+                    is_start: true,
+                    segment,
+                });
+                self.events.push(Event {
+                    point: b,
+                    is_start: false,
+                    segment,
+                });
+            }
+        }
+
+        // at the same point segments are removed before new ones are inserted
+        self.events
+            .sort_unstable_by(|e0, e1| e0.point.cmp(&e1.point).then(e0.is_start.cmp(&e1.is_start)));
+    }
+
+    fn edge_pair(&self, i: usize, j: usize) -> (EdgeRef, EdgeRef) {
+        let e0 = self.segments[i].edge_ref();
+        let e1 = self.segments[j].edge_ref();
+        if e0 < e1 { (e0, e1) } else { (e1, e0) }
+    }
+
+    fn is_intersect(&self, i: usize, j: usize) -> bool {
+        let s0 = &self.segments[i];
+        let s1 = &self.segments[j];
+
+        if is_adjacent(s0.edge_ref(), s1.edge_ref(), s0.count) {
+            // adjacent edges intersect only if they fold back along the same line
+            let v = if s0.a == s1.a || s0.a == s1.b { s0.a } else { s0.b };
+            let p0 = if s0.a == v { s0.b } else { s0.a };
+            let p1 = if s1.a == v { s1.b } else { s1.a };
+            return orient(v, p0, p1) == 0 && dot(v, p0, p1) > 0;
+        }
+
+        let d0 = orient(s1.a, s1.b, s0.a).signum();
+        let d1 = orient(s1.a, s1.b, s0.b).signum();
+        let d2 = orient(s0.a, s0.b, s1.a).signum();
+        let d3 = orient(s0.a, s0.b, s1.b).signum();
+
+        if d0 * d1 < 0 && d2 * d3 < 0 {
+            return true;
+        }
+
+        d0 == 0 && is_on_segment(s0.a, s1.a, s1.b)
+            || d1 == 0 && is_on_segment(s0.b, s1.a, s1.b)
+            || d2 == 0 && is_on_segment(s1.a, s0.a, s0.b)
+            || d3 == 0 && is_on_segment(s1.b, s0.a, s0.b)
+    }
+}
+
+impl Segment {
+    #[inline(always)]
+    fn edge_ref(&self) -> EdgeRef {
+        EdgeRef {
+            contour: self.contour,
+            edge: self.edge,
+        }
+    }
+}
+
+impl Vertex {
+    #[inline(always)]
+    fn incident_edges(&self) -> [EdgeRef; 2] {
+        let prev = (self.index + self.count - 1) % self.count;
+        [
+            EdgeRef {
+                contour: self.contour,
+                edge: prev,
+            },
+            EdgeRef {
+                contour: self.contour,
+                edge: self.index,
+            },
+        ]
+    }
+}
+
+#[inline(always)]
+fn is_adjacent(e0: EdgeRef, e1: EdgeRef, count: usize) -> bool {
+    e0.contour == e1.contour && ((e0.edge + 1) % count == e1.edge || (e1.edge + 1) % count == e0.edge)
+}
+
+impl ActiveKey {
+    /// Compares two segments which are both crossed by the sweep line.
+    /// The segment which starts later is tested against the line of the other one.
+    fn order(&self, other: &Self) -> Ordering {
+        let o = if self.a == other.a {
+            orient(other.a, other.b, self.b)
+        } else if self.a > other.a {
+            orient(other.a, other.b, self.a)
+        } else {
+            -orient(self.a, self.b, other.a)
+        };
+
+        match o.cmp(&0) {
+            // touching or collinear segments, they will be caught as neighbors
+            Ordering::Equal => self.id.cmp(&other.id),
+            ordering => ordering,
+        }
+    }
+}
+
+impl PartialEq for ActiveKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for ActiveKey {}
+
+impl PartialOrd for ActiveKey {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ActiveKey {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.id == other.id {
+            Ordering::Equal
+        } else {
+            self.order(other)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int::intersection::{EdgePair, SelfIntersection, ShapeIntersection};
+    use crate::{int_path, int_shape};
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use rand::rngs::StdRng;
+    use rand::{RngExt, SeedableRng};
+
+    #[test]
+    fn test_0() {
+        let contour = int_path![[0, 0], [0, 10], [10, 10], [10, 0],];
+
+        assert!(contour.find_self_intersection().is_none());
+    }
+
+    #[test]
+    fn test_1() {
+        // figure-eight
+        let contour = int_path![[0, 0], [10, 10], [10, 0], [0, 10],];
+
+        assert_eq!(contour.find_self_intersection(), Some(EdgePair { a: 0, b: 2 }));
+    }
+
+    #[test]
+    fn test_2() {
+        // touches itself at [5, 0]
+        let contour = int_path![[0, 0], [5, 5], [10, 0], [10, 10], [0, 10], [5, 0],];
+
+        assert!(contour.has_self_intersection());
+    }
+
+    #[test]
+    fn test_3() {
+        // vertex [5, 0] lies on the edge [0, 0] - [10, 0]
+        let contour = int_path![[0, 0], [10, 0], [10, 10], [5, 0], [0, 10],];
+
+        assert!(contour.has_self_intersection());
+    }
+
+    #[test]
+    fn test_4() {
+        // spike folds back along the same line
+        let contour = int_path![[0, 0], [0, 10], [10, 10], [10, 0], [5, 0], [8, 0],];
+
+        assert!(contour.has_self_intersection());
+    }
+
+    #[test]
+    fn test_5() {
+        // collinear vertices are fine
+        let contour = int_path![[0, 0], [0, 5], [0, 10], [5, 10], [10, 10], [10, 0],];
+
+        assert!(!contour.has_self_intersection());
+    }
+
+    #[test]
+    fn test_6() {
+        let shape = int_shape![
+            [[-10, -10], [-10, 10], [10, 10], [10, -10],],
+            [[-5, -5], [5, -5], [5, 5], [-5, 5],],
+        ];
+        assert!(shape.find_intersection().is_none());
+
+        let shape = int_shape![
+            [[-10, -10], [-10, 10], [10, 10], [10, -10],],
+            [[-5, -5], [15, -5], [15, 5], [-5, 5],],
+        ];
+        let (e0, e1) = shape.find_intersection().unwrap();
+        assert_eq!(e0.contour, 0);
+        assert_eq!(e1.contour, 1);
+    }
+
+    #[test]
+    fn test_7() {
+        let max = i32::MAX;
+        let min = i32::MIN;
+        let contour = int_path![[min, min], [max, max], [max, min], [min, max],];
+
+        assert!(contour.has_self_intersection());
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20_000 {
+            let n = rng.random_range(3..9);
+            let contour: Vec<IntPoint> = (0..n)
+                .map(|_| IntPoint::new(rng.random_range(-4..4), rng.random_range(-4..4)))
+                .collect();
+
+            let expected = brute_force(&contour);
+            let result = contour.find_self_intersection();
+            assert_eq!(result.is_some(), expected, "{:?}", contour);
+        }
+    }
+
+    /// Tests every pair of edges directly against the rules of `SelfIntersection`.
+    fn brute_force(contour: &[IntPoint]) -> bool {
+        let n = contour.len();
+        let edge = |e: usize| (contour[e], contour[(e + 1) % n]);
+        for e0 in 0..n {
+            for e1 in e0 + 1..n {
+                let is_intersect = if e1 == e0 + 1 || e0 == 0 && e1 == n - 1 {
+                    // the previous edge ends where the next one starts
+                    let (prev, next) = if e1 == e0 + 1 { (e0, e1) } else { (e1, e0) };
+                    let (p0, v) = edge(prev);
+                    let p1 = edge(next).1;
+                    p0 != v && p1 != v && cross(v, p0, p1) == 0 && dot(v, p0, p1) > 0
+                } else {
+                    let (a0, a1) = edge(e0);
+                    let (b0, b1) = edge(e1);
+                    is_segments_touch(a0, a1, b0, b1)
+                };
+                if is_intersect {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn cross(o: IntPoint, a: IntPoint, b: IntPoint) -> i64 {
+        let (ax, ay) = (a.x as i64 - o.x as i64, a.y as i64 - o.y as i64);
+        let (bx, by) = (b.x as i64 - o.x as i64, b.y as i64 - o.y as i64);
+        ax * by - ay * bx
+    }
+
+    fn dot(o: IntPoint, a: IntPoint, b: IntPoint) -> i64 {
+        let (ax, ay) = (a.x as i64 - o.x as i64, a.y as i64 - o.y as i64);
+        let (bx, by) = (b.x as i64 - o.x as i64, b.y as i64 - o.y as i64);
+        ax * bx + ay * by
+    }
+
+    fn is_on_segment(p: IntPoint, a: IntPoint, b: IntPoint) -> bool {
+        cross(a, b, p) == 0
+            && a.x.min(b.x) <= p.x
+            && p.x <= a.x.max(b.x)
+            && a.y.min(b.y) <= p.y
+            && p.y <= a.y.max(b.y)
+    }
+
+    fn is_segments_touch(a0: IntPoint, a1: IntPoint, b0: IntPoint, b1: IntPoint) -> bool {
+        let d0 = cross(b0, b1, a0).signum();
+        let d1 = cross(b0, b1, a1).signum();
+        let d2 = cross(a0, a1, b0).signum();
+        let d3 = cross(a0, a1, b1).signum();
+        if d0 * d1 < 0 && d2 * d3 < 0 {
+            return true;
+        }
+        is_on_segment(a0, b0, b1)
+            || is_on_segment(a1, b0, b1)
+            || is_on_segment(b0, a0, a1)
+            || is_on_segment(b1, a0, a1)
+    }
+}
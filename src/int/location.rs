@@ -0,0 +1,206 @@
+use crate::flat::buffer::FlatShapesBuffer;
+use crate::flat::view::ShapeView;
+use crate::int::exact::{is_on_segment, orient};
+use crate::int::shape::IntContour;
+use i_float::int::point::IntPoint;
+
+/// The position of a point relative to a contour or a shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointLocation {
+    Inside,
+    Outside,
+    OnBoundary,
+}
+
+pub trait ClassifyPoint {
+    /// Classifies a point against the area bounded by the structure.
+    ///
+    /// All tests are exact integer orientation tests, so the result is deterministic
+    /// for any `i32` coordinates and points lying exactly on an edge are reported as
+    /// `PointLocation::OnBoundary`.
+    fn classify_point(&self, point: IntPoint) -> PointLocation;
+}
+
+impl ClassifyPoint for [IntPoint] {
+    fn classify_point(&self, point: IntPoint) -> PointLocation {
+        let mut a = if let Some(&p) = self.last() {
+            p
+        } else {
+            return PointLocation::Outside;
+        };
+
+        let mut is_inside = false;
+        for &b in self.iter() {
+            if is_on_segment(point, a, b) {
+                return PointLocation::OnBoundary;
+            }
+
+            if (a.y > point.y) != (b.y > point.y) {
+                // the ray to the right crosses the edge if the point is on the left side of an upward edge
+                // or on the right side of a downward edge
+                let is_left = orient(a, b, point) > 0;
+                if is_left == (b.y > a.y) {
+                    is_inside = !is_inside;
+                }
+            }
+            a = b;
+        }
+
+        if is_inside {
+            PointLocation::Inside
+        } else {
+            PointLocation::Outside
+        }
+    }
+}
+
+impl ClassifyPoint for [IntContour] {
+    /// The first contour is treated as the outer boundary and the rest as holes.
+    #[inline]
+    fn classify_point(&self, point: IntPoint) -> PointLocation {
+        classify_shape(self.iter().map(|contour| contour.as_slice()), point)
+    }
+}
+
+impl ClassifyPoint for ShapeView<'_> {
+    /// The outer contour is treated as the outer boundary and the rest as holes.
+    #[inline]
+    fn classify_point(&self, point: IntPoint) -> PointLocation {
+        classify_shape(self.contours(), point)
+    }
+}
+
+impl ClassifyPoint for FlatShapesBuffer {
+    /// A point is inside the buffer if it is inside any of its shapes.
+    fn classify_point(&self, point: IntPoint) -> PointLocation {
+        let mut result = PointLocation::Outside;
+        for shape_range in self.shape_ranges.iter() {
+            let contours = self.contour_ranges[shape_range.clone()]
+                .iter()
+                .map(|range| &self.points[range.clone()]);
+
+            match classify_shape(contours, point) {
+                PointLocation::Inside => return PointLocation::Inside,
+                PointLocation::OnBoundary => result = PointLocation::OnBoundary,
+                PointLocation::Outside => {}
+            }
+        }
+
+        result
+    }
+}
+
+fn classify_shape<'a, I>(mut contours: I, point: IntPoint) -> PointLocation
+where
+    I: Iterator<Item = &'a [IntPoint]>,
+{
+    let outer = if let Some(outer) = contours.next() {
+        outer
+    } else {
+        return PointLocation::Outside;
+    };
+
+    match outer.classify_point(point) {
+        PointLocation::Inside => {}
+        location => return location,
+    }
+
+    for hole in contours {
+        match hole.classify_point(point) {
+            PointLocation::Inside => return PointLocation::Outside,
+            PointLocation::OnBoundary => return PointLocation::OnBoundary,
+            PointLocation::Outside => {}
+        }
+    }
+
+    PointLocation::Inside
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::int::location::{ClassifyPoint, PointLocation};
+    use crate::{int_path, int_shape, int_shapes};
+    use i_float::int::point::IntPoint;
+
+    #[test]
+    fn test_0() {
+        let contour = int_path![[0, 0], [0, 10], [10, 10], [10, 0],];
+
+        assert_eq!(contour.classify_point(IntPoint::new(5, 5)), PointLocation::Inside);
+        assert_eq!(
+            contour.classify_point(IntPoint::new(15, 5)),
+            PointLocation::Outside
+        );
+        assert_eq!(
+            contour.classify_point(IntPoint::new(0, 5)),
+            PointLocation::OnBoundary
+        );
+        assert_eq!(
+            contour.classify_point(IntPoint::new(10, 10)),
+            PointLocation::OnBoundary
+        );
+        assert_eq!(
+            contour.classify_point(IntPoint::new(-1, 10)),
+            PointLocation::Outside
+        );
+    }
+
+    #[test]
+    fn test_1() {
+        let max = i32::MAX;
+        let min = i32::MIN;
+        let contour = int_path![[min, min], [max, 0], [min, max],];
+
+        assert_eq!(contour.classify_point(IntPoint::new(0, 0)), PointLocation::Inside);
+        assert_eq!(
+            contour.classify_point(IntPoint::new(max, 1)),
+            PointLocation::Outside
+        );
+        assert_eq!(
+            contour.classify_point(IntPoint::new(max, 0)),
+            PointLocation::OnBoundary
+        );
+        assert_eq!(
+            contour.classify_point(IntPoint::new(min, 0)),
+            PointLocation::OnBoundary
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        let shape = int_shape![
+            [[-10, -10], [-10, 10], [10, 10], [10, -10],],
+            [[-5, -5], [5, -5], [5, 5], [-5, 5],],
+        ];
+
+        assert_eq!(shape.classify_point(IntPoint::new(0, 0)), PointLocation::Outside);
+        assert_eq!(shape.classify_point(IntPoint::new(7, 0)), PointLocation::Inside);
+        assert_eq!(
+            shape.classify_point(IntPoint::new(5, 0)),
+            PointLocation::OnBoundary
+        );
+        assert_eq!(shape.classify_point(IntPoint::new(20, 0)), PointLocation::Outside);
+    }
+
+    #[test]
+    fn test_3() {
+        let shapes = int_shapes![
+            [[[0, 0], [0, 10], [10, 10], [10, 0],],],
+            [[[20, 0], [20, 10], [30, 10], [30, 0],],],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        assert_eq!(buffer.classify_point(IntPoint::new(25, 5)), PointLocation::Inside);
+        assert_eq!(
+            buffer.classify_point(IntPoint::new(15, 5)),
+            PointLocation::Outside
+        );
+        assert_eq!(
+            buffer.classify_point(IntPoint::new(20, 5)),
+            PointLocation::OnBoundary
+        );
+    }
+}
@@ -1,9 +1,21 @@
 pub mod area;
+pub mod clean;
 pub mod count;
 pub mod dedup;
 pub mod despike;
+pub(crate) mod exact;
+pub mod hull;
+pub mod intersection;
+pub mod location;
+pub mod moments;
+pub mod nest;
 pub mod path;
+pub mod rect;
+pub mod reduce;
 pub mod reverse;
 pub mod shape;
 pub mod simple;
+pub mod transform;
+pub mod validate;
+pub mod winding;
 pub use i_float::int::point::IntPoint;
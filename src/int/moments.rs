@@ -0,0 +1,382 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::int::shape::{IntContour, IntShape};
+use crate::util::wide::I256;
+use i_float::float::number::FloatNumber;
+use i_float::float::point::FloatPoint;
+use i_float::int::point::IntPoint;
+
+/// Area properties of a region.
+#[derive(Debug, Clone, Copy)]
+pub struct Moments {
+    /// The area, holes are subtracted.
+    pub area: f64,
+    pub centroid: FloatPoint<f64>,
+    /// The second moment `∫(y - cy)² dA` about the horizontal axis through the centroid.
+    pub ixx: f64,
+    /// The second moment `∫(x - cx)² dA` about the vertical axis through the centroid.
+    pub iyy: f64,
+    /// The product moment `∫(x - cx)(y - cy) dA` about the centroid.
+    pub ixy: f64,
+}
+
+/// A trait for area properties of contours and shapes.
+///
+/// The sums are accumulated exactly in wide integers and only the final values are rounded,
+/// so the result is stable for any `i32` coordinates.
+/// The region does not depend on the contour direction:
+/// an outer contour always adds its area and holes always subtract theirs.
+pub trait ShapeMoments {
+    /// Returns the area properties, `None` if the area is zero.
+    fn moments(&self) -> Option<Moments>;
+
+    /// The total length of all contours, holes included.
+    fn perimeter(&self) -> f64;
+
+    #[inline]
+    fn centroid(&self) -> Option<FloatPoint<f64>> {
+        self.moments().map(|moments| moments.centroid)
+    }
+}
+
+impl ShapeMoments for [IntPoint] {
+    #[inline]
+    fn moments(&self) -> Option<Moments> {
+        let origin = *self.first()?;
+        let mut sum = MomentSum::default();
+        sum.add_contour(self, origin, true);
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> f64 {
+        contour_perimeter(self)
+    }
+}
+
+impl ShapeMoments for [IntContour] {
+    #[inline]
+    fn moments(&self) -> Option<Moments> {
+        let origin = *self.first()?.first()?;
+        let mut sum = MomentSum::default();
+        sum.add_shape(self.iter().map(|contour| contour.as_slice()), origin);
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> f64 {
+        self.iter().map(|contour| contour_perimeter(contour)).sum()
+    }
+}
+
+impl ShapeMoments for [IntShape] {
+    fn moments(&self) -> Option<Moments> {
+        let origin = *self.iter().flatten().flatten().next()?;
+        let mut sum = MomentSum::default();
+        for shape in self.iter() {
+            sum.add_shape(shape.iter().map(|contour| contour.as_slice()), origin);
+        }
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> f64 {
+        self.iter().map(|shape| shape.perimeter()).sum()
+    }
+}
+
+/// The contours are independent, each one adds its area with the sign of its direction
+/// as in `ExactArea`, so a contour in the opposite direction subtracts its area like a hole.
+/// The regions are the contours whose direction gives a positive total area.
+impl ShapeMoments for FlatContoursBuffer {
+    fn moments(&self) -> Option<Moments> {
+        let range = self.ranges.iter().find(|range| !range.is_empty())?;
+        let origin = self.points[range.start];
+        let mut sum = MomentSum::default();
+        for range in self.ranges.iter() {
+            sum.add(&MomentSum::with_contour(&self.points[range.clone()], origin));
+        }
+        if sum.s.is_negative() {
+            sum = sum.negated();
+        }
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> f64 {
+        self.ranges
+            .iter()
+            .map(|range| contour_perimeter(&self.points[range.clone()]))
+            .sum()
+    }
+}
+
+impl ShapeMoments for FlatShapesBuffer {
+    fn moments(&self) -> Option<Moments> {
+        let range = self.contour_ranges.iter().find(|range| !range.is_empty())?;
+        let origin = self.points[range.start];
+        let mut sum = MomentSum::default();
+        for shape_range in self.shape_ranges.iter() {
+            let contours = self.contour_ranges[shape_range.clone()]
+                .iter()
+                .map(|range| &self.points[range.clone()]);
+            sum.add_shape(contours, origin);
+        }
+        sum.moments(origin)
+    }
+
+    #[inline]
+    fn perimeter(&self) -> f64 {
+        self.contour_ranges
+            .iter()
+            .map(|range| contour_perimeter(&self.points[range.clone()]))
+            .sum()
+    }
+}
+
+/// Green's theorem sums relative to an origin, for a counter-clockwise contour:
+/// `s = 2A`, `sx = 6∫x`, `sy = 6∫y`, `sxx = 12∫x²`, `syy = 12∫y²`, `sxy = 24∫xy`.
+#[derive(Default)]
+struct MomentSum {
+    s: I256,
+    sx: I256,
+    sy: I256,
+    sxx: I256,
+    syy: I256,
+    sxy: I256,
+}
+
+impl MomentSum {
+    fn add_shape<'a, I: Iterator<Item = &'a [IntPoint]>>(&mut self, contours: I, origin: IntPoint) {
+        for (index, contour) in contours.enumerate() {
+            self.add_contour(contour, origin, index == 0);
+        }
+    }
+
+    fn add_contour(&mut self, contour: &[IntPoint], origin: IntPoint, is_outer: bool) {
+        let sum = MomentSum::with_contour(contour, origin);
+
+        // outer contours add and holes subtract regardless of the direction
+        let is_positive = !sum.s.is_negative() && sum.s != I256::ZERO;
+        if is_positive == is_outer {
+            self.add(&sum);
+        } else {
+            self.add(&sum.negated());
+        }
+    }
+
+    /// The sums of a single contour, positive for a counter-clockwise contour.
+    fn with_contour(contour: &[IntPoint], origin: IntPoint) -> MomentSum {
+        let mut sum = MomentSum::default();
+        let mut s: i128 = 0;
+
+        let ox = origin.x as i128;
+        let oy = origin.y as i128;
+        let mut p0 = if let Some(p) = contour.last() {
+            *p
+        } else {
+            return sum;
+        };
+        for &p1 in contour.iter() {
+            let (x0, y0) = (p0.x as i128 - ox, p0.y as i128 - oy);
+            let (x1, y1) = (p1.x as i128 - ox, p1.y as i128 - oy);
+            let c = x0 * y1 - x1 * y0;
+
+            s += c;
+            sum.sx += I256::mul(x0 + x1, c);
+            sum.sy += I256::mul(y0 + y1, c);
+            sum.sxx += I256::mul(x0 * x0 + x0 * x1 + x1 * x1, c);
+            sum.syy += I256::mul(y0 * y0 + y0 * y1 + y1 * y1, c);
+            sum.sxy += I256::mul(x0 * y1 + 2 * x0 * y0 + 2 * x1 * y1 + x1 * y0, c);
+
+            p0 = p1;
+        }
+        sum.s = I256::from_i128(s);
+
+        sum
+    }
+
+    #[inline]
+    fn add(&mut self, other: &MomentSum) {
+        self.s += other.s;
+        self.sx += other.sx;
+        self.sy += other.sy;
+        self.sxx += other.sxx;
+        self.syy += other.syy;
+        self.sxy += other.sxy;
+    }
+
+    #[inline]
+    fn negated(&self) -> MomentSum {
+        MomentSum {
+            s: self.s.neg(),
+            sx: self.sx.neg(),
+            sy: self.sy.neg(),
+            sxx: self.sxx.neg(),
+            syy: self.syy.neg(),
+            sxy: self.sxy.neg(),
+        }
+    }
+
+    fn moments(&self, origin: IntPoint) -> Option<Moments> {
+        if self.s == I256::ZERO {
+            return None;
+        }
+
+        let s = self.s.to_f64();
+        let area = 0.5 * s;
+        let cx = self.sx.to_f64() / (3.0 * s);
+        let cy = self.sy.to_f64() / (3.0 * s);
+
+        // the parallel axis theorem, the origin is close to the region so the cancellation is small
+        let ixx = self.syy.to_f64() / 12.0 - area * cy * cy;
+        let iyy = self.sxx.to_f64() / 12.0 - area * cx * cx;
+        let ixy = self.sxy.to_f64() / 24.0 - area * cx * cy;
+
+        Some(Moments {
+            area,
+            centroid: FloatPoint::new(origin.x as f64 + cx, origin.y as f64 + cy),
+            ixx,
+            iyy,
+            ixy,
+        })
+    }
+}
+
+#[inline]
+fn contour_perimeter(contour: &[IntPoint]) -> f64 {
+    let mut p0 = if let Some(p) = contour.last() {
+        *p
+    } else {
+        return 0.0;
+    };
+
+    let mut length = 0.0;
+    for &p1 in contour.iter() {
+        let dx = (p1.x as i64 - p0.x as i64) as f64;
+        let dy = (p1.y as i64 - p0.y as i64) as f64;
+        length += FloatNumber::sqrt(dx * dx + dy * dy);
+        p0 = p1;
+    }
+
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::int::area::ExactArea;
+    use crate::int::moments::{Moments, ShapeMoments};
+    use crate::int::path::ContourExtension;
+    use crate::{int_path, int_shape, int_shapes};
+
+    fn values(m: Moments) -> [f64; 6] {
+        [m.area, m.centroid.x, m.centroid.y, m.ixx, m.iyy, m.ixy]
+    }
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_0() {
+        // a 4 x 2 rectangle
+        let contour = int_path![[0, 0], [4, 0], [4, 2], [0, 2],];
+        let m = contour.moments().unwrap();
+
+        assert_eq!(m.area, 8.0);
+        assert_eq!((m.centroid.x, m.centroid.y), (2.0, 1.0));
+        assert_near(m.ixx, 4.0 * 8.0 / 12.0);
+        assert_near(m.iyy, 2.0 * 64.0 / 12.0);
+        assert_eq!(m.ixy, 0.0);
+        assert_eq!(contour.perimeter(), 12.0);
+
+        // the direction does not matter
+        assert_eq!(values(contour.to_reversed().moments().unwrap()), values(m));
+        assert!(int_path![[0, 0], [1, 0], [2, 0],].moments().is_none());
+    }
+
+    #[test]
+    fn test_1() {
+        // a square with a hole in the right part
+        let shape = int_shape![
+            [[0, 0], [4, 0], [4, 4], [0, 4],],
+            [[2, 1], [3, 1], [3, 3], [2, 3],],
+        ];
+        let m = shape.moments().unwrap();
+
+        assert_eq!(m.area, 14.0);
+        assert_near(m.centroid.x, (16.0 * 2.0 - 2.0 * 2.5) / 14.0);
+        assert_eq!(m.centroid.y, 2.0);
+        assert_eq!(shape.perimeter(), 22.0);
+    }
+
+    #[test]
+    fn test_2() {
+        // huge coordinates far from the origin
+        let x = i32::MAX - 10;
+        let y = i32::MIN + 10;
+        let shapes = int_shapes![
+            [[[x - 4, y], [x, y], [x, y + 2], [x - 4, y + 2],],],
+            [[[-x, -y], [-x + 4, -y], [-x + 4, -y + 2], [-x, -y + 2],],],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let m = buffer.moments().unwrap();
+        assert_eq!(values(shapes.moments().unwrap()), values(m));
+        assert_eq!(m.area, 16.0);
+        assert_eq!((m.centroid.x, m.centroid.y), (0.0, 1.0));
+
+        let m0 = shapes[0][0].moments().unwrap();
+        assert_eq!((m0.centroid.x, m0.centroid.y), (x as f64 - 2.0, y as f64 + 1.0));
+        assert_near(m0.ixx, 4.0 * 8.0 / 12.0);
+    }
+
+    #[test]
+    fn test_3() {
+        let shape = int_shape![
+            [[0, 0], [4, 0], [4, 4], [0, 4],],
+            [[2, 1], [2, 3], [3, 3], [3, 1],],
+        ];
+
+        let mut contours = FlatContoursBuffer::default();
+        contours.set_with_shape(&shape);
+        let mut shapes = FlatShapesBuffer::default();
+        shapes.set_with_shape(&shape);
+
+        let m = shape.moments().unwrap();
+        assert_eq!(values(contours.moments().unwrap()), values(m));
+        assert_eq!(values(shapes.moments().unwrap()), values(m));
+        assert_eq!(contours.perimeter(), shape.perimeter());
+        assert_eq!(shapes.perimeter(), shape.perimeter());
+
+        assert!(FlatContoursBuffer::default().moments().is_none());
+        assert!(FlatShapesBuffer::default().moments().is_none());
+    }
+
+    #[test]
+    fn test_4() {
+        // two disjoint squares are two regions, not an outer contour and a hole
+        let squares = int_shapes![
+            [[[0, 0], [2, 0], [2, 2], [0, 2],],],
+            [[[4, 0], [6, 0], [6, 2], [4, 2],],],
+        ];
+
+        let mut buffer = FlatContoursBuffer::default();
+        buffer.set_with_shapes(&squares);
+
+        let m = buffer.moments().unwrap();
+        assert_eq!(values(m), values(squares.moments().unwrap()));
+        assert_eq!(m.area, 8.0);
+        assert_eq!((m.centroid.x, m.centroid.y), (3.0, 1.0));
+        assert_eq!(m.area, buffer.exact_area_two().unsigned_abs() as f64 / 2.0);
+
+        // the same regions in the clockwise direction
+        let mut reversed = FlatContoursBuffer::default();
+        for range in buffer.ranges.iter() {
+            reversed.add_contour(&buffer.points[range.clone()].to_reversed());
+        }
+        assert_eq!(values(reversed.moments().unwrap()), values(m));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::base::direction::ContourDirection;
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::int::area::ExactArea;
+use crate::int::location::{ClassifyPoint, PointLocation};
+use crate::int::reverse::IntContourOrientation;
+use crate::int::shape::{IntContour, IntShapes};
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_float::int::rect::IntRect;
+
+/// A trait for building shapes from an unordered set of contours.
+///
+/// Contours are expected not to cross each other, touching is allowed.
+/// A contour at an even nesting depth becomes an outer contour,
+/// a contour at an odd depth becomes a hole of the contour directly containing it.
+/// Contours with zero area are skipped.
+///
+/// The contours are swept along the x-axis and only the ones overlapping the sweep line
+/// are checked as parents, so disjoint or nested layouts take `O(n log n)` plus the point tests.
+/// Contours sharing the same x-range are all checked against each other,
+/// in the worst case (a single column of contours) the bounds checks take `O(n^2)`.
+pub trait NestContours {
+    /// Returns the nested shapes, the outer contour of every shape has `main_direction`
+    /// and holes have the opposite one.
+    fn to_nested_shapes(&self, main_direction: ContourDirection) -> IntShapes;
+
+    /// The same as `to_nested_shapes` but writes the result into a flat buffer.
+    fn to_nested_flat_shapes(&self, main_direction: ContourDirection) -> FlatShapesBuffer;
+}
+
+impl NestContours for [IntContour] {
+    #[inline]
+    fn to_nested_shapes(&self, main_direction: ContourDirection) -> IntShapes {
+        let contours: Vec<&[IntPoint]> = self.iter().map(|contour| contour.as_slice()).collect();
+        nested_shapes(&contours, main_direction)
+    }
+
+    #[inline]
+    fn to_nested_flat_shapes(&self, main_direction: ContourDirection) -> FlatShapesBuffer {
+        let contours: Vec<&[IntPoint]> = self.iter().map(|contour| contour.as_slice()).collect();
+        nested_flat_shapes(&contours, main_direction)
+    }
+}
+
+impl NestContours for FlatContoursBuffer {
+    #[inline]
+    fn to_nested_shapes(&self, main_direction: ContourDirection) -> IntShapes {
+        let contours: Vec<&[IntPoint]> = self
+            .ranges
+            .iter()
+            .map(|range| &self.points[range.clone()])
+            .collect();
+        nested_shapes(&contours, main_direction)
+    }
+
+    #[inline]
+    fn to_nested_flat_shapes(&self, main_direction: ContourDirection) -> FlatShapesBuffer {
+        let contours: Vec<&[IntPoint]> = self
+            .ranges
+            .iter()
+            .map(|range| &self.points[range.clone()])
+            .collect();
+        nested_flat_shapes(&contours, main_direction)
+    }
+}
+
+fn nested_shapes(contours: &[&[IntPoint]], main_direction: ContourDirection) -> IntShapes {
+    let mut shapes: IntShapes = group(contours)
+        .into_iter()
+        .map(|indices| indices.into_iter().map(|i| contours[i].to_vec()).collect())
+        .collect();
+
+    shapes.normalize_orientation(main_direction);
+    shapes
+}
+
+fn nested_flat_shapes(contours: &[&[IntPoint]], main_direction: ContourDirection) -> FlatShapesBuffer {
+    let groups = group(contours);
+
+    let points_len = groups.iter().flatten().map(|&i| contours[i].len()).sum();
+    let contours_len = groups.iter().map(Vec::len).sum();
+    let mut buffer = FlatShapesBuffer::with_capacity(points_len, contours_len, groups.len());
+
+    for indices in groups.iter() {
+        let shape_start = buffer.contour_ranges.len();
+        for &i in indices.iter() {
+            let start = buffer.points.len();
+            buffer.points.extend_from_slice(contours[i]);
+            buffer.contour_ranges.push(start..buffer.points.len());
+        }
+        buffer.shape_ranges.push(shape_start..buffer.contour_ranges.len());
+    }
+
+    buffer.normalize_orientation(main_direction);
+    buffer
+}
+
+/// Returns the contour indices of every shape, the outer contour goes first.
+fn group(contours: &[&[IntPoint]]) -> Vec<Vec<usize>> {
+    let areas: Vec<u128> = contours
+        .iter()
+        .map(|contour| contour.exact_area_two().unsigned_abs())
+        .collect();
+    // empty contours have zero area and are never used
+    let rects: Vec<IntRect> = contours
+        .iter()
+        .map(|contour| IntRect::with_points(contour).unwrap_or(IntRect::new(0, 0, 0, 0)))
+        .collect();
+
+    // a containing contour starts not later and is bigger
+    let mut order: Vec<usize> = (0..contours.len()).filter(|&i| areas[i] > 0).collect();
+    order.sort_unstable_by(|&a, &b| rects[a].min_x.cmp(&rects[b].min_x).then(areas[b].cmp(&areas[a])));
+
+    // the shape index for outer contours and the parent shape index for holes
+    let mut shape_of = vec![usize::MAX; contours.len()];
+    let mut depth = vec![0; contours.len()];
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+
+    // contours crossing the sweep line, sorted by max_x in descending order
+    let mut active: Vec<usize> = Vec::new();
+
+    for &index in order.iter() {
+        let rect = &rects[index];
+
+        // contours ending before this one starts are at the tail
+        let alive = active.partition_point(|&i| rects[i].max_x >= rect.min_x);
+        active.truncate(alive);
+
+        // only the contours ending not before this one can contain it
+        let candidates = active.partition_point(|&i| rects[i].max_x >= rect.max_x);
+
+        // the direct parent is the smallest contour containing this one
+        let mut parent = usize::MAX;
+        for &i in active[..candidates].iter() {
+            if !rects[i].contains_rect(rect) || parent != usize::MAX && areas[parent] <= areas[i] {
+                continue;
+            }
+            if is_inside(contours[i], contours[index]) {
+                parent = i;
+            }
+        }
+
+        if parent == usize::MAX || depth[parent] % 2 == 1 {
+            depth[index] = if parent == usize::MAX {
+                0
+            } else {
+                depth[parent] + 1
+            };
+            shape_of[index] = groups.len();
+            groups.push(vec![index]);
+        } else {
+            depth[index] = depth[parent] + 1;
+            let shape = shape_of[parent];
+            shape_of[index] = shape;
+            groups[shape].push(index);
+        }
+
+        active.insert(candidates, index);
+    }
+
+    groups
+}
+
+/// Checks if `inner` is inside `outer`, assuming the contours do not cross.
+#[inline]
+fn is_inside(outer: &[IntPoint], inner: &[IntPoint]) -> bool {
+    for &p in inner.iter() {
+        match outer.classify_point(p) {
+            PointLocation::Inside => return true,
+            PointLocation::Outside => return false,
+            PointLocation::OnBoundary => {}
+        }
+    }
+
+    // all vertices are on the boundary, check the edge middles
+    let mut p0 = inner[inner.len() - 1];
+    for &p1 in inner.iter() {
+        let x = ((p0.x as i64 + p1.x as i64) >> 1) as i32;
+        let y = ((p0.y as i64 + p1.y as i64) >> 1) as i32;
+        let m = IntPoint::new(x, y);
+        if m != p0 && m != p1 {
+            match outer.classify_point(m) {
+                PointLocation::Inside => return true,
+                PointLocation::Outside => return false,
+                PointLocation::OnBoundary => {}
+            }
+        }
+        p0 = p1;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::direction::ContourDirection;
+    use crate::flat::buffer::FlatContoursBuffer;
+    use crate::int::nest::NestContours;
+    use crate::int::path::IntPaths;
+    use crate::int::validate::Validate;
+    use crate::{int_path, int_shapes};
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use rand::RngExt;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        int_path![[x, y], [x + size, y], [x + size, y + size], [x, y + size],]
+    }
+
+    #[test]
+    fn test_0() {
+        let contours: IntPaths = alloc::vec![
+            square(2, 2, 6),
+            square(20, 0, 5),
+            square(0, 0, 10),
+            square(4, 4, 2),
+        ];
+
+        let shapes = contours.to_nested_shapes(ContourDirection::CounterClockwise);
+        assert_eq!(
+            shapes,
+            int_shapes![
+                [
+                    [[0, 0], [10, 0], [10, 10], [0, 10],],
+                    [[2, 8], [8, 8], [8, 2], [2, 2],],
+                ],
+                [[[4, 4], [6, 4], [6, 6], [4, 6],],],
+                [[[20, 0], [25, 0], [25, 5], [20, 5],],],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_1() {
+        // holes touching the outer contour and each other
+        let contours: IntPaths = alloc::vec![square(0, 0, 5), square(5, 0, 5), square(0, 0, 10),];
+
+        let mut buffer = FlatContoursBuffer::default();
+        for contour in contours.iter() {
+            buffer.add_contour(contour);
+        }
+
+        let flat = buffer.to_nested_flat_shapes(ContourDirection::CounterClockwise);
+        assert_eq!(flat.shape_ranges.len(), 1);
+        assert_eq!(flat.contour_ranges.len(), 3);
+        assert_eq!(
+            flat.to_shapes(),
+            contours.to_nested_shapes(ContourDirection::CounterClockwise)
+        );
+        assert_eq!(flat.to_shapes()[0][0], square(0, 0, 10));
+    }
+
+    #[test]
+    fn test_2() {
+        // 4800 contours: every cell has an outer contour, a hole and an island
+        let mut contours: IntPaths = Vec::new();
+        for cx in 0..40 {
+            for cy in 0..40 {
+                contours.push(square(cx * 10, cy * 10, 9));
+                contours.push(square(cx * 10 + 2, cy * 10 + 2, 5));
+                contours.push(square(cx * 10 + 3, cy * 10 + 3, 3));
+            }
+        }
+        contours.reverse();
+
+        let shapes = contours.to_nested_shapes(ContourDirection::CounterClockwise);
+        assert_eq!(shapes.len(), 3200);
+        assert_eq!(shapes.iter().filter(|shape| shape.len() == 2).count(), 1600);
+        assert!(shapes.validate().is_empty());
+    }
+
+    #[test]
+    fn test_random() {
+        // concentric squares in a grid of cells
+        let mut rng = rand::rng();
+        for _ in 0..100 {
+            let mut contours: IntPaths = Vec::new();
+            let mut expected = 0;
+            for cx in 0..5 {
+                for cy in 0..5 {
+                    let count: usize = rng.random_range(0..5);
+                    expected += count.div_ceil(2);
+                    for k in 0..count {
+                        let k = k as i32;
+                        contours.push(square(cx * 100 + 5 * k, cy * 100 + 5 * k, 90 - 10 * k));
+                    }
+                }
+            }
+
+            for i in (1..contours.len()).rev() {
+                let j = rng.random_range(0..=i);
+                contours.swap(i, j);
+            }
+
+            let shapes = contours.to_nested_shapes(ContourDirection::CounterClockwise);
+            assert_eq!(shapes.len(), expected);
+            assert!(shapes.validate().is_empty());
+        }
+    }
+}
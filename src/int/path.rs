@@ -1,3 +1,5 @@
+use crate::int::area::ExactArea;
+use crate::int::exact::orient;
 use crate::int::shape::IntContour;
 use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
@@ -75,15 +77,18 @@ impl ContourExtension for [IntPoint] {
     }
 
     /// The wind direction of the `Path`.
+    /// The area is accumulated exactly, so the answer is correct for any `i32` coordinates.
     /// - Returns: A Boolean value indicating whether the path is clockwise ordered.
     ///  - Returns `true` if the path is clockwise ordered.
     ///  - Returns `false` otherwise.
     #[inline(always)]
     fn is_clockwise_ordered(&self) -> bool {
-        self.unsafe_area() >= 0
+        self.exact_area_two() >= 0
     }
 
     /// Checks if a point is contained within the `Path`.
+    /// Points lying exactly on an edge may be reported either way,
+    /// use `ClassifyPoint::classify_point` to detect them.
     /// - Parameter p: The `IntPoint` point to check.
     /// - Returns: A boolean value indicating whether the point is within the path.
     fn contains(&self, point: IntPoint) -> bool {
@@ -93,10 +98,9 @@ impl ContourExtension for [IntPoint] {
         for &a in self.iter() {
             let is_in_range = (a.y > point.y) != (b.y > point.y);
             if is_in_range {
-                let dx = b.x - a.x;
-                let dy = b.y - a.y;
-                let sx = (point.y - a.y) * dx / dy + a.x;
-                if point.x < sx {
+                // point is on the left side of the edge relative to its upward direction
+                let is_left = orient(a, b, point) > 0;
+                if is_left == (b.y > a.y) {
                     is_contain = !is_contain;
                 }
             }
@@ -118,6 +122,7 @@ impl ContourExtension for [IntPoint] {
 mod tests {
     use crate::int::path::ContourExtension;
     use crate::int_path;
+    use i_float::int::point::IntPoint;
 
     #[test]
     fn test_0() {
@@ -137,4 +142,27 @@ mod tests {
         assert!(area < 0);
         assert!(abs_area > 1);
     }
+
+    #[test]
+    fn test_1() {
+        let max = i32::MAX;
+        let min = i32::MIN;
+        let contour = int_path![[min, min], [min, max], [max, max], [max, min],];
+
+        assert!(contour.is_clockwise_ordered());
+        assert!(!contour.to_reversed().is_clockwise_ordered());
+    }
+
+    #[test]
+    fn test_2() {
+        let max = i32::MAX;
+        let min = i32::MIN;
+        let contour = int_path![[min, min], [max, 0], [min, max],];
+        let contains = |x: i32, y: i32| ContourExtension::contains(contour.as_slice(), IntPoint::new(x, y));
+
+        assert!(contains(0, 0));
+        assert!(contains(min + 1, max - 1));
+        assert!(!contains(min + 10, max - 1));
+        assert!(!contains(max, 1));
+    }
 }
@@ -0,0 +1,152 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::int::shape::{IntContour, IntShape};
+use alloc::vec::Vec;
+use core::ops::Range;
+use i_float::int::point::IntPoint;
+use i_float::int::rect::IntRect;
+
+pub trait IntRectInit {
+    fn with_contour(contour: &[IntPoint]) -> Option<IntRect>;
+    fn with_shape(shape: &[IntContour]) -> Option<IntRect>;
+    fn with_shapes(shapes: &[IntShape]) -> Option<IntRect>;
+    fn with_flat_contours(buffer: &FlatContoursBuffer) -> Option<IntRect>;
+    fn with_flat_shapes(buffer: &FlatShapesBuffer) -> Option<IntRect>;
+}
+
+pub trait IntRectExtension {
+    /// The smallest rect containing both rects.
+    fn union(&self, other: &IntRect) -> IntRect;
+
+    /// The common part of both rects, borders included.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(IntRect)` the intersection, it can have zero width or height if the rects only touch.
+    /// - `None` if the rects do not overlap.
+    fn intersection(&self, other: &IntRect) -> Option<IntRect>;
+}
+
+/// A trait for computing a bounding box of every contour in a flat buffer.
+pub trait ContourRects {
+    /// Returns the bounding box of every contour, `None` for an empty contour.
+    fn contour_rects(&self) -> Vec<Option<IntRect>>;
+}
+
+impl IntRectInit for IntRect {
+    #[inline]
+    fn with_contour(contour: &[IntPoint]) -> Option<IntRect> {
+        IntRect::with_points(contour)
+    }
+
+    #[inline]
+    fn with_shape(shape: &[IntContour]) -> Option<IntRect> {
+        IntRect::with_iter(shape.iter().flatten())
+    }
+
+    #[inline]
+    fn with_shapes(shapes: &[IntShape]) -> Option<IntRect> {
+        IntRect::with_iter(shapes.iter().flatten().flatten())
+    }
+
+    #[inline]
+    fn with_flat_contours(buffer: &FlatContoursBuffer) -> Option<IntRect> {
+        with_ranges(&buffer.points, &buffer.ranges)
+    }
+
+    #[inline]
+    fn with_flat_shapes(buffer: &FlatShapesBuffer) -> Option<IntRect> {
+        with_ranges(&buffer.points, &buffer.contour_ranges)
+    }
+}
+
+impl IntRectExtension for IntRect {
+    #[inline]
+    fn union(&self, other: &IntRect) -> IntRect {
+        IntRect::with_rects(self, other)
+    }
+
+    #[inline]
+    fn intersection(&self, other: &IntRect) -> Option<IntRect> {
+        if !self.is_intersect_border_include(other) {
+            return None;
+        }
+
+        Some(IntRect::new(
+            self.min_x.max(other.min_x),
+            self.max_x.min(other.max_x),
+            self.min_y.max(other.min_y),
+            self.max_y.min(other.max_y),
+        ))
+    }
+}
+
+impl ContourRects for FlatContoursBuffer {
+    #[inline]
+    fn contour_rects(&self) -> Vec<Option<IntRect>> {
+        self.ranges
+            .iter()
+            .map(|range| IntRect::with_points(&self.points[range.clone()]))
+            .collect()
+    }
+}
+
+impl ContourRects for FlatShapesBuffer {
+    #[inline]
+    fn contour_rects(&self) -> Vec<Option<IntRect>> {
+        self.contour_ranges
+            .iter()
+            .map(|range| IntRect::with_points(&self.points[range.clone()]))
+            .collect()
+    }
+}
+
+#[inline]
+fn with_ranges(points: &[IntPoint], ranges: &[Range<usize>]) -> Option<IntRect> {
+    IntRect::with_iter(ranges.iter().flat_map(|range| points[range.clone()].iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::int::rect::{ContourRects, IntRectExtension, IntRectInit};
+    use crate::{int_path, int_shapes};
+    use i_float::int::rect::IntRect;
+
+    fn sides(rect: &IntRect) -> [i32; 4] {
+        [rect.min_x, rect.max_x, rect.min_y, rect.max_y]
+    }
+
+    #[test]
+    fn test_0() {
+        let shapes = int_shapes![
+            [[[0, 0], [10, 0], [10, 10], [0, 10],], [[2, 2], [2, 8], [8, 8],],],
+            [[[20, -5], [30, -5], [30, 5],],],
+        ];
+
+        let rect = IntRect::with_shapes(&shapes).unwrap();
+        assert_eq!(sides(&rect), [0, 30, -5, 10]);
+        assert_eq!(sides(&IntRect::with_shape(&shapes[1]).unwrap()), [20, 30, -5, 5]);
+        assert!(IntRect::with_contour(&int_path![]).is_none());
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+        assert_eq!(sides(&IntRect::with_flat_shapes(&buffer).unwrap()), sides(&rect));
+
+        let rects = buffer.contour_rects();
+        assert_eq!(rects.len(), 3);
+        assert_eq!(sides(rects[1].as_ref().unwrap()), [2, 8, 2, 8]);
+    }
+
+    #[test]
+    fn test_1() {
+        let a = IntRect::new(0, 10, 0, 10);
+        let b = IntRect::new(5, 15, -5, 5);
+        let c = IntRect::new(10, 20, 10, 20);
+        let d = IntRect::new(11, 20, 0, 20);
+
+        assert_eq!(sides(&a.union(&b)), [0, 15, -5, 10]);
+        assert_eq!(sides(&a.intersection(&b).unwrap()), [5, 10, 0, 5]);
+        assert_eq!(sides(&a.intersection(&c).unwrap()), [10, 10, 10, 10]);
+        assert!(a.intersection(&d).is_none());
+    }
+}
@@ -0,0 +1,660 @@
+use crate::int::area::ExactArea;
+use crate::int::exact::orient;
+use crate::int::intersection::{EdgeSweep, ShapeIntersection};
+use crate::int::location::{ClassifyPoint, PointLocation};
+use crate::int::path::IntPath;
+use crate::int::shape::{IntContour, IntShape, IntShapes};
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use i_float::int::point::IntPoint;
+
+/// The algorithm and the tolerance used to reduce the number of points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReduceMethod {
+    /// Douglas–Peucker: removes points closer than `max_distance` to the reduced polyline.
+    DouglasPeucker { max_distance: f64 },
+    /// Visvalingam–Whyatt: repeatedly removes the point forming the smallest triangle
+    /// with its neighbors while that triangle area is below `min_area`.
+    VisvalingamWhyatt { min_area: f64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReduceOptions {
+    pub method: ReduceMethod,
+    /// If `true`, a reduced contour never intersects itself or other contours of its shape,
+    /// keeps its orientation and a hole stays inside the outer contour.
+    /// Conflicting contours are reduced again with a smaller tolerance
+    /// and keep their original points as the last resort.
+    pub preserve_topology: bool,
+}
+
+/// A trait for tolerance-based point reduction of a single contour or an open path.
+pub trait ReduceContour {
+    /// Returns a reduced copy of the closed contour.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(IntContour)` with at least 3 not collinear points.
+    /// - `None` if the contour is degenerate (fewer than 3 points or all points are collinear).
+    fn reduced_contour(&self, options: &ReduceOptions) -> Option<IntContour>;
+
+    /// Returns a reduced copy of the open path. The first and the last points are always kept.
+    fn reduced_path(&self, method: ReduceMethod) -> IntPath;
+}
+
+/// A trait for tolerance-based point reduction of a shape.
+pub trait ReduceShape {
+    /// Returns a reduced copy of the shape. Degenerate holes are dropped.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(IntShape)` containing the reduced shape.
+    /// - `None` if the outer contour is degenerate.
+    fn reduced_shape(&self, options: &ReduceOptions) -> Option<IntShape>;
+}
+
+/// A trait that reduces the number of points in-place.
+pub trait Reduce {
+    /// Reduces the structure in-place, every contour is treated as closed.
+    /// Degenerate contours are removed, a shape is removed with its outer contour.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if any point was removed.
+    /// - `false` if the structure was not modified.
+    fn reduce(&mut self, options: &ReduceOptions) -> bool;
+}
+
+impl ReduceContour for [IntPoint] {
+    fn reduced_contour(&self, options: &ReduceOptions) -> Option<IntContour> {
+        let mut reducer = Reducer::default();
+        let contour = reducer.reduce_closed(self, options.method)?;
+        if !options.preserve_topology {
+            return Some(contour);
+        }
+
+        let sign = self.exact_area_two().signum();
+        if reducer.is_topology_kept(&contour, sign) {
+            return Some(contour);
+        }
+
+        // retry with a smaller tolerance
+        let mut method = options.method;
+        for _ in 0..MAX_REFINE_LEVEL {
+            method = method.refined();
+            if let Some(contour) = reducer.reduce_closed(self, method)
+                && reducer.is_topology_kept(&contour, sign)
+            {
+                return Some(contour);
+            }
+        }
+
+        Some(self.to_vec())
+    }
+
+    #[inline]
+    fn reduced_path(&self, method: ReduceMethod) -> IntPath {
+        Reducer::default().reduce_open(self, method)
+    }
+}
+
+impl ReduceShape for [IntContour] {
+    fn reduced_shape(&self, options: &ReduceOptions) -> Option<IntShape> {
+        let mut reducer = Reducer::default();
+        let mut shape = Vec::with_capacity(self.len());
+        let mut sources = Vec::with_capacity(self.len());
+        for (i, contour) in self.iter().enumerate() {
+            if let Some(reduced) = reducer.reduce_closed(contour, options.method) {
+                shape.push(reduced);
+                sources.push(i);
+            } else if i == 0 {
+                return None;
+            }
+        }
+
+        if !options.preserve_topology {
+            return Some(shape);
+        }
+
+        let signs: Vec<i128> = sources
+            .iter()
+            .map(|&source| self[source].exact_area_two().signum())
+            .collect();
+
+        let mut levels = vec![0; shape.len()];
+        while let Some(conflict) = find_conflict(&shape, &signs) {
+            let count = if conflict[0] == conflict[1] { 1 } else { 2 };
+            let mut is_refined = false;
+            for &contour in conflict[..count].iter() {
+                let level = &mut levels[contour];
+                if *level > MAX_REFINE_LEVEL {
+                    continue;
+                }
+                *level += 1;
+                is_refined = true;
+
+                let source = &self[sources[contour]];
+                let method = options.method.refined_by(*level);
+                shape[contour] = if *level > MAX_REFINE_LEVEL {
+                    source.clone()
+                } else {
+                    reducer
+                        .reduce_closed(source, method)
+                        .unwrap_or_else(|| source.clone())
+                };
+            }
+
+            if !is_refined {
+                // the source shape itself is not valid
+                break;
+            }
+        }
+
+        Some(shape)
+    }
+}
+
+impl Reduce for IntContour {
+    fn reduce(&mut self, options: &ReduceOptions) -> bool {
+        let n = self.len();
+        if let Some(contour) = self.reduced_contour(options) {
+            *self = contour;
+        } else {
+            self.clear();
+        }
+        self.len() < n
+    }
+}
+
+impl Reduce for IntShape {
+    fn reduce(&mut self, options: &ReduceOptions) -> bool {
+        let n = self.iter().fold(0, |acc, contour| acc + contour.len());
+        if let Some(shape) = self.reduced_shape(options) {
+            *self = shape;
+        } else {
+            self.clear();
+        }
+        self.iter().fold(0, |acc, contour| acc + contour.len()) < n
+    }
+}
+
+impl Reduce for IntShapes {
+    fn reduce(&mut self, options: &ReduceOptions) -> bool {
+        let mut any_reduced = false;
+        let mut any_empty = false;
+
+        for shape in self.iter_mut() {
+            any_reduced |= shape.reduce(options);
+            any_empty |= shape.is_empty();
+        }
+
+        if any_empty {
+            self.retain(|shape| !shape.is_empty());
+        }
+
+        any_reduced
+    }
+}
+
+const MAX_REFINE_LEVEL: u32 = 8;
+
+/// Searches for contours of a reduced shape which break the topology of the source shape.
+///
+/// # Returns
+///
+/// - `Some([i, j])` with the conflicting contours, `i == j` for a single contour.
+/// - `None` if the shape has no conflicts.
+fn find_conflict(shape: &[IntContour], signs: &[i128]) -> Option<[usize; 2]> {
+    for (i, contour) in shape.iter().enumerate() {
+        if signs[i] != 0 && contour.exact_area_two().signum() != signs[i] {
+            return Some([i, i]);
+        }
+    }
+
+    if let Some((e0, e1)) = shape.find_intersection() {
+        return Some([e0.contour, e1.contour]);
+    }
+
+    // without intersections a hole is inside if any of its points is
+    let outer = shape[0].as_slice();
+    for (i, hole) in shape.iter().enumerate().skip(1) {
+        if outer.classify_point(hole[0]) != PointLocation::Inside {
+            return Some([0, i]);
+        }
+    }
+
+    None
+}
+
+impl ReduceMethod {
+    #[inline]
+    fn refined(self) -> Self {
+        self.refined_by(1)
+    }
+
+    #[inline]
+    fn refined_by(self, level: u32) -> Self {
+        let scale = 1.0 / (1u64 << level) as f64;
+        match self {
+            ReduceMethod::DouglasPeucker { max_distance } => ReduceMethod::DouglasPeucker {
+                max_distance: max_distance * scale,
+            },
+            ReduceMethod::VisvalingamWhyatt { min_area } => ReduceMethod::VisvalingamWhyatt {
+                min_area: min_area * scale,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Link {
+    prev: usize,
+    next: usize,
+    stamp: u32,
+}
+
+#[derive(Default)]
+struct Reducer {
+    keep: Vec<bool>,
+    stack: Vec<(usize, usize)>,
+    links: Vec<Link>,
+    heap: BinaryHeap<Reverse<(i128, usize, u32)>>,
+    sweep: EdgeSweep,
+}
+
+impl Reducer {
+    /// Checks that the reduced contour does not intersect itself and keeps the source orientation.
+    #[inline]
+    fn is_topology_kept(&mut self, contour: &[IntPoint], sign: i128) -> bool {
+        (sign == 0 || contour.exact_area_two().signum() == sign) && self.sweep.find(&[contour]).is_none()
+    }
+
+    fn reduce_closed(&mut self, points: &[IntPoint], method: ReduceMethod) -> Option<IntContour> {
+        let n = points.len();
+        if n < 3 || is_degenerate(points) {
+            return None;
+        }
+
+        match method {
+            ReduceMethod::DouglasPeucker { max_distance } => {
+                self.douglas_peucker_closed(points, max_distance)
+            }
+            ReduceMethod::VisvalingamWhyatt { min_area } => self.visvalingam(points, min_area, true),
+        }
+
+        let contour = self.collect(points);
+        if is_degenerate(&contour) {
+            // can only happen if everything except a straight line was removed
+            return Some(points.to_vec());
+        }
+
+        Some(contour)
+    }
+
+    fn reduce_open(&mut self, points: &[IntPoint], method: ReduceMethod) -> IntPath {
+        if points.len() < 3 {
+            return points.to_vec();
+        }
+
+        match method {
+            ReduceMethod::DouglasPeucker { max_distance } => {
+                self.keep.clear();
+                self.keep.resize(points.len(), false);
+                self.keep[0] = true;
+                self.keep[points.len() - 1] = true;
+                self.douglas_peucker(points, 0, points.len() - 1, max_distance);
+            }
+            ReduceMethod::VisvalingamWhyatt { min_area } => self.visvalingam(points, min_area, false),
+        }
+
+        self.collect(points)
+    }
+
+    fn douglas_peucker_closed(&mut self, points: &[IntPoint], max_distance: f64) {
+        let n = points.len();
+        self.keep.clear();
+        self.keep.resize(n, false);
+
+        // split the contour at the first point and the point farthest from it
+        let p0 = points[0];
+        let mut far = 0;
+        let mut far_dist = 0.0;
+        for (i, &p) in points.iter().enumerate() {
+            let dist = sqr_distance(p0, p);
+            if dist > far_dist {
+                far_dist = dist;
+                far = i;
+            }
+        }
+
+        self.keep[0] = true;
+        self.keep[far] = true;
+        self.douglas_peucker(points, 0, far, max_distance);
+        self.douglas_peucker(points, far, n, max_distance);
+
+        if self.keep.iter().filter(|&&keep| keep).count() < 3 {
+            // keep the point farthest from the split line
+            let p1 = points[far];
+            let mut best = 0;
+            let mut best_dist = 0.0;
+            for (i, &p) in points.iter().enumerate() {
+                let dist = sqr_segment_distance(p, p0, p1);
+                if dist > best_dist {
+                    best_dist = dist;
+                    best = i;
+                }
+            }
+            self.keep[best] = true;
+        }
+    }
+
+    /// Marks points to keep between `start` and `end`, the index `n` is the same as `0`.
+    fn douglas_peucker(&mut self, points: &[IntPoint], start: usize, end: usize, max_distance: f64) {
+        let n = points.len();
+        let sqr_max_distance = max_distance * max_distance;
+
+        self.stack.clear();
+        self.stack.push((start, end));
+        while let Some((i0, i1)) = self.stack.pop() {
+            if i1 <= i0 + 1 {
+                continue;
+            }
+            let a = points[i0 % n];
+            let b = points[i1 % n];
+
+            let mut index = i0;
+            let mut max_dist = 0.0;
+            for (i, &p) in points.iter().enumerate().take(i1).skip(i0 + 1) {
+                let dist = sqr_segment_distance(p, a, b);
+                if dist > max_dist {
+                    max_dist = dist;
+                    index = i;
+                }
+            }
+
+            if max_dist > sqr_max_distance {
+                self.keep[index] = true;
+                self.stack.push((i0, index));
+                self.stack.push((index, i1));
+            }
+        }
+    }
+
+    fn visvalingam(&mut self, points: &[IntPoint], min_area: f64, is_closed: bool) {
+        let n = points.len();
+        let min_count = if is_closed { 3 } else { 2 };
+        let max_area_two = 2.0 * min_area;
+
+        self.keep.clear();
+        self.keep.resize(n, true);
+
+        self.links.clear();
+        for i in 0..n {
+            self.links.push(Link {
+                prev: (i + n - 1) % n,
+                next: (i + 1) % n,
+                stamp: 0,
+            });
+        }
+
+        self.heap.clear();
+        let (first, last) = if is_closed { (0, n) } else { (1, n - 1) };
+        for i in first..last {
+            let area = self.triangle_area(points, i);
+            self.heap.push(Reverse((area, i, 0)));
+        }
+
+        let mut count = n;
+        while let Some(Reverse((area, i, stamp))) = self.heap.pop() {
+            if !self.keep[i] || self.links[i].stamp != stamp {
+                continue;
+            }
+            if count <= min_count || area as f64 >= max_area_two {
+                break;
+            }
+
+            self.keep[i] = false;
+            count -= 1;
+
+            let Link { prev, next, .. } = self.links[i];
+            self.links[prev].next = next;
+            self.links[next].prev = prev;
+
+            for j in [prev, next] {
+                if !is_closed && (j == 0 || j == n - 1) {
+                    continue;
+                }
+                self.links[j].stamp += 1;
+                let area = self.triangle_area(points, j);
+                self.heap.push(Reverse((area, j, self.links[j].stamp)));
+            }
+        }
+    }
+
+    #[inline]
+    fn triangle_area(&self, points: &[IntPoint], i: usize) -> i128 {
+        let link = self.links[i];
+        orient(points[link.prev], points[i], points[link.next]).abs()
+    }
+
+    #[inline]
+    fn collect(&self, points: &[IntPoint]) -> Vec<IntPoint> {
+        points
+            .iter()
+            .zip(self.keep.iter())
+            .filter(|(_, keep)| **keep)
+            .map(|(p, _)| *p)
+            .collect()
+    }
+}
+
+#[inline]
+fn is_degenerate(points: &[IntPoint]) -> bool {
+    let p0 = points[0];
+    let p1 = if let Some(&p) = points.iter().find(|&&p| p != p0) {
+        p
+    } else {
+        return true;
+    };
+    points.iter().all(|&p| orient(p0, p1, p) == 0)
+}
+
+#[inline]
+fn sqr_distance(a: IntPoint, b: IntPoint) -> f64 {
+    let dx = (b.x as i64 - a.x as i64) as f64;
+    let dy = (b.y as i64 - a.y as i64) as f64;
+    dx * dx + dy * dy
+}
+
+/// The squared distance from `p` to the segment `a`, `b`.
+#[inline]
+fn sqr_segment_distance(p: IntPoint, a: IntPoint, b: IntPoint) -> f64 {
+    let abx = (b.x as i64 - a.x as i64) as f64;
+    let aby = (b.y as i64 - a.y as i64) as f64;
+    let apx = (p.x as i64 - a.x as i64) as f64;
+    let apy = (p.y as i64 - a.y as i64) as f64;
+
+    let sqr_len = abx * abx + aby * aby;
+    if sqr_len == 0.0 {
+        return apx * apx + apy * apy;
+    }
+
+    let t = ((apx * abx + apy * aby) / sqr_len).clamp(0.0, 1.0);
+    let dx = apx - t * abx;
+    let dy = apy - t * aby;
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int::area::ExactArea;
+    use crate::int::intersection::{SelfIntersection, ShapeIntersection};
+    use crate::int::location::{ClassifyPoint, PointLocation};
+    use crate::int::reduce::{Reduce, ReduceContour, ReduceMethod, ReduceOptions, ReduceShape};
+    use crate::{int_path, int_shape, int_shapes};
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+
+    const DP: ReduceOptions = ReduceOptions {
+        method: ReduceMethod::DouglasPeucker { max_distance: 1.5 },
+        preserve_topology: false,
+    };
+
+    const VW: ReduceOptions = ReduceOptions {
+        method: ReduceMethod::VisvalingamWhyatt { min_area: 10.0 },
+        preserve_topology: false,
+    };
+
+    #[test]
+    fn test_0() {
+        // noisy square
+        let contour = int_path![
+            [0, 0],
+            [5, 1],
+            [10, 0],
+            [11, 5],
+            [10, 10],
+            [5, 9],
+            [0, 10],
+            [-1, 5],
+        ];
+
+        let dp = contour.reduced_contour(&DP).unwrap();
+        assert_eq!(dp, int_path![[0, 0], [10, 0], [10, 10], [0, 10],]);
+
+        let vw = contour.reduced_contour(&VW).unwrap();
+        assert_eq!(vw, int_path![[0, 0], [10, 0], [10, 10], [0, 10],]);
+    }
+
+    #[test]
+    fn test_1() {
+        // a huge tolerance still keeps a triangle
+        let contour = int_path![[0, 0], [10, 0], [10, 10], [0, 10],];
+        let options = ReduceOptions {
+            method: ReduceMethod::DouglasPeucker { max_distance: 100.0 },
+            preserve_topology: false,
+        };
+        assert_eq!(contour.reduced_contour(&options).unwrap().len(), 3);
+
+        let options = ReduceOptions {
+            method: ReduceMethod::VisvalingamWhyatt { min_area: 1000.0 },
+            preserve_topology: false,
+        };
+        assert_eq!(contour.reduced_contour(&options).unwrap().len(), 3);
+
+        let line = int_path![[0, 0], [5, 0], [10, 0],];
+        assert!(line.reduced_contour(&DP).is_none());
+    }
+
+    #[test]
+    fn test_2() {
+        let path = int_path![[0, 0], [5, 1], [10, 0], [15, -1], [20, 0],];
+
+        let method = ReduceMethod::DouglasPeucker { max_distance: 1.5 };
+        assert_eq!(path.reduced_path(method), int_path![[0, 0], [20, 0],]);
+
+        // only the collinear middle point is removed
+        let method = ReduceMethod::VisvalingamWhyatt { min_area: 1.0 };
+        assert_eq!(
+            path.reduced_path(method),
+            int_path![[0, 0], [5, 1], [15, -1], [20, 0],]
+        );
+    }
+
+    #[test]
+    fn test_3() {
+        // the hole is close to the outer contour, a careless reduction crosses it
+        let shape = int_shape![
+            [[0, 0], [20, 0], [20, 10], [10, 12], [0, 10],],
+            [[5, 5], [5, 10], [15, 10], [15, 5],],
+        ];
+
+        let mut options = ReduceOptions {
+            method: ReduceMethod::DouglasPeucker { max_distance: 3.0 },
+            preserve_topology: false,
+        };
+        let reduced = shape.reduced_shape(&options).unwrap();
+        assert!(reduced.find_intersection().is_some());
+
+        options.preserve_topology = true;
+        let reduced = shape.reduced_shape(&options).unwrap();
+        assert!(reduced.find_intersection().is_none());
+        assert_eq!(reduced[1].len(), 4);
+    }
+
+    #[test]
+    fn test_4() {
+        let mut shapes = int_shapes![
+            [[[0, 0], [5, 1], [10, 0], [10, 10], [0, 10],],],
+            [[[0, 0], [5, 0], [10, 0],],],
+        ];
+
+        assert!(shapes.reduce(&DP));
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0][0].len(), 4);
+    }
+
+    #[test]
+    fn test_5() {
+        // zig-zag contour which self-intersects after a rough reduction
+        let mut contour: Vec<IntPoint> = Vec::new();
+        for i in 0..10 {
+            contour.push(IntPoint::new(i * 10, if i % 2 == 0 { 0 } else { 4 }));
+        }
+        for i in (0..10).rev() {
+            contour.push(IntPoint::new(i * 10, if i % 2 == 0 { 6 } else { 10 }));
+        }
+        assert!(!contour.has_self_intersection());
+
+        let options = ReduceOptions {
+            method: ReduceMethod::VisvalingamWhyatt { min_area: 60.0 },
+            preserve_topology: true,
+        };
+        let reduced = contour.reduced_contour(&options).unwrap();
+        assert!(reduced.len() < contour.len());
+        assert!(!reduced.has_self_intersection());
+    }
+
+    #[test]
+    fn test_6() {
+        // the hole sits in a small bump which a rough reduction cuts off
+        let shape = int_shape![
+            [
+                [0, 0],
+                [40, 0],
+                [40, 10],
+                [22, 10],
+                [22, 14],
+                [18, 14],
+                [18, 10],
+                [0, 10],
+            ],
+            [[19, 11], [19, 13], [21, 13], [21, 11],],
+        ];
+
+        let mut options = ReduceOptions {
+            method: ReduceMethod::DouglasPeucker { max_distance: 5.0 },
+            preserve_topology: false,
+        };
+        let reduced = shape.reduced_shape(&options).unwrap();
+        assert!(reduced.find_intersection().is_none());
+        assert_eq!(
+            reduced[0][..].classify_point(reduced[1][0]),
+            PointLocation::Outside
+        );
+
+        options.preserve_topology = true;
+        let reduced = shape.reduced_shape(&options).unwrap();
+        assert!(reduced.find_intersection().is_none());
+        assert_eq!(
+            reduced[0][..].classify_point(reduced[1][0]),
+            PointLocation::Inside
+        );
+        assert_eq!(
+            reduced[0].exact_area_two().signum(),
+            shape[0].exact_area_two().signum()
+        );
+    }
+}
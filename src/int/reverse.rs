@@ -1,9 +1,23 @@
+use crate::base::direction::ContourDirection;
+use crate::flat::buffer::FlatShapesBuffer;
+use crate::int::area::ExactArea;
 use crate::int::shape::{IntShape, IntShapes};
+use i_float::int::point::IntPoint;
 
 pub trait IntContourReverse {
     fn reverse_contours(&mut self);
 }
 
+pub trait IntContourOrientation {
+    /// Reverses contours in-place so the outer contour of every shape has `main_direction`
+    /// and all holes have the opposite one. Contours with zero area are left as is.
+    ///
+    /// # Returns
+    ///
+    /// - The number of reversed contours.
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize;
+}
+
 impl IntContourReverse for IntShape {
     #[inline]
     fn reverse_contours(&mut self) {
@@ -23,3 +37,107 @@ impl IntContourReverse for IntShapes {
         }
     }
 }
+
+impl IntContourOrientation for IntShape {
+    #[inline]
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize {
+        let mut count = 0;
+        for (index, contour) in self.iter_mut().enumerate() {
+            count += orient_contour(contour, direction(index, main_direction)) as usize;
+        }
+        count
+    }
+}
+
+impl IntContourOrientation for IntShapes {
+    #[inline]
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize {
+        self.iter_mut()
+            .map(|shape| shape.normalize_orientation(main_direction))
+            .sum()
+    }
+}
+
+impl IntContourOrientation for FlatShapesBuffer {
+    fn normalize_orientation(&mut self, main_direction: ContourDirection) -> usize {
+        let mut count = 0;
+        for shape_range in self.shape_ranges.iter() {
+            let contour_ranges = &self.contour_ranges[shape_range.clone()];
+            for (index, range) in contour_ranges.iter().enumerate() {
+                let contour = &mut self.points[range.clone()];
+                count += orient_contour(contour, direction(index, main_direction)) as usize;
+            }
+        }
+        count
+    }
+}
+
+#[inline]
+pub(crate) fn direction(index: usize, main_direction: ContourDirection) -> ContourDirection {
+    if index == 0 {
+        main_direction
+    } else {
+        main_direction.reversed()
+    }
+}
+
+/// Returns `true` if the contour was reversed.
+#[inline]
+fn orient_contour(contour: &mut [IntPoint], direction: ContourDirection) -> bool {
+    let area = contour.exact_area_two();
+    let is_clockwise = area > 0;
+    if area == 0 || is_clockwise == (direction == ContourDirection::Clockwise) {
+        return false;
+    }
+    contour.reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::direction::ContourDirection;
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::int::reverse::IntContourOrientation;
+    use crate::int_shapes;
+
+    #[test]
+    fn test_0() {
+        let mut shapes = int_shapes![
+            [
+                [[-10, -10], [-10, 10], [10, 10], [10, -10],],
+                [[-5, -5], [-5, 5], [5, 5], [5, -5],],
+                [[0, 0], [1, 0], [2, 0],],
+            ],
+            [[[20, 0], [30, 0], [30, 10],],],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let ccw = int_shapes![
+            [
+                [[10, -10], [10, 10], [-10, 10], [-10, -10],],
+                [[-5, -5], [-5, 5], [5, 5], [5, -5],],
+                [[0, 0], [1, 0], [2, 0],],
+            ],
+            [[[20, 0], [30, 0], [30, 10],],],
+        ];
+
+        assert_eq!(
+            shapes.normalize_orientation(ContourDirection::CounterClockwise),
+            1
+        );
+        assert_eq!(shapes, ccw);
+        assert_eq!(
+            shapes.normalize_orientation(ContourDirection::CounterClockwise),
+            0
+        );
+        assert_eq!(shapes.normalize_orientation(ContourDirection::Clockwise), 3);
+
+        assert_eq!(
+            buffer.normalize_orientation(ContourDirection::CounterClockwise),
+            1
+        );
+        assert_eq!(buffer.to_shapes(), ccw);
+    }
+}
@@ -1,31 +1,53 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::int::clean::ContourCleaner;
+use crate::int::exact::orient;
+use crate::int::intersection::SelfIntersection;
 use crate::int::shape::{IntContour, IntShape, IntShapes};
-use alloc::vec;
 use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 
 /// A trait that provides methods for simplifying complex geometrical structures.
 pub trait Simplify {
-    /// Simplifies the structure in-place if it is not already simple.
+    /// Removes duplicate points, collinear points and spikes in-place,
+    /// so the result is clean (see `SimpleContour::is_clean`).
+    ///
+    /// Self-intersections are not resolved, a figure-eight stays a figure-eight
+    /// and `is_simple` remains `false` for it.
     ///
     /// # Returns
     ///
     /// - `true` if the structure was simplified successfully.
-    /// - `false` if the structure was already simple and no modification was made.
+    /// - `false` if the structure was already clean and no modification was made.
     fn simplify_contour(&mut self) -> bool;
 }
 
 /// A trait for determining if a contour is simple and for obtaining a simplified version.
 pub trait SimpleContour {
-    /// Checks if the contour is already simple, meaning it has no self-intersections
-    /// and meets the minimum complexity required.
+    /// Checks if the contour is clean, meaning it meets the minimum complexity
+    /// required and has no collinear or coincident consecutive points.
+    ///
+    /// This is what `Simplify` and `simplified` establish. The check is linear and does not allocate.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the contour is clean.
+    /// - `false` if the contour has too few points, duplicates, collinear points or spikes.
+    fn is_clean(&self) -> bool;
+
+    /// Checks if the contour is simple, meaning it is clean (see `is_clean`)
+    /// and no two edges cross or touch each other (see `SelfIntersection`).
+    ///
+    /// `Simplify` does not resolve self-intersections, so a simplified contour is clean
+    /// but not necessarily simple. The intersection test is a sweep line,
+    /// it allocates and runs in `O(n log n)`.
     ///
     /// # Returns
     ///
     /// - `true` if the contour is simple.
-    /// - `false` if the contour is complex or does not meet simplicity criteria.
+    /// - `false` if the contour is not clean or intersects itself.
     fn is_simple(&self) -> bool;
 
-    /// Returns an optional simplified version of the contour.
+    /// Returns an optional clean version of the contour, self-intersections are kept.
     ///
     /// # Returns
     ///
@@ -37,15 +59,21 @@ pub trait SimpleContour {
 /// A trait for determining if a shape, composed of multiple contours, is simple,
 /// and for obtaining a simplified version.
 pub trait SimpleShape {
+    /// Checks if all contours of the shape are clean, see `SimpleContour::is_clean`.
+    fn is_clean(&self) -> bool;
+
     /// Checks if the shape is simple, meaning all its contours are simple.
     ///
+    /// Intersections between different contours are not checked,
+    /// use `ShapeIntersection::find_intersection` for that.
+    ///
     /// # Returns
     ///
     /// - `true` if all contours in the shape are simple.
     /// - `false` if any contour is complex.
     fn is_simple(&self) -> bool;
 
-    /// Returns an optional simplified version of the shape.
+    /// Returns an optional clean version of the shape, self-intersections are kept.
     ///
     /// # Returns
     ///
@@ -57,6 +85,9 @@ pub trait SimpleShape {
 /// A trait for determining if a collection of shapes is simple, and for obtaining
 /// a simplified version of the entire collection.
 pub trait SimpleShapes {
+    /// Checks if all contours in the collection are clean, see `SimpleContour::is_clean`.
+    fn is_clean(&self) -> bool;
+
     /// Checks if all shapes in the collection are simple.
     ///
     /// # Returns
@@ -65,7 +96,7 @@ pub trait SimpleShapes {
     /// - `false` if any shape in the collection is complex.
     fn is_simple(&self) -> bool;
 
-    /// Returns an optional simplified version of the collection.
+    /// Returns a clean version of the collection, self-intersections are kept.
     ///
     /// # Returns
     ///
@@ -76,77 +107,47 @@ pub trait SimpleShapes {
 impl Simplify for IntContour {
     #[inline]
     fn simplify_contour(&mut self) -> bool {
-        if self.is_simple() {
-            return false;
-        }
-        if let Some(contour) = self.simplified() {
-            self.clear();
-            self.extend(contour);
-        } else {
-            self.clear()
-        }
-        true
+        ContourCleaner::default().clean_contour(self)
     }
 }
 
 impl Simplify for IntShape {
+    #[inline]
     fn simplify_contour(&mut self) -> bool {
-        let mut any_simplified = false;
-        let mut any_empty = false;
-
-        for (index, contour) in self.iter_mut().enumerate() {
-            if contour.is_simple() {
-                continue;
-            }
-            any_simplified = true;
-
-            if let Some(simple_contour) = contour.simplified() {
-                contour.clear();
-                contour.extend(simple_contour);
-            } else if index == 0 {
-                // early out main contour is empty
-                self.clear();
-                return true;
-            } else {
-                contour.clear();
-                any_empty = true;
-            }
-        }
-
-        if any_empty {
-            self.retain(|contour| !contour.is_empty());
-        }
-
-        any_simplified
+        ContourCleaner::default().clean_shape(self)
     }
 }
 
 impl Simplify for IntShapes {
+    #[inline]
     fn simplify_contour(&mut self) -> bool {
-        let mut any_simplified = false;
-        let mut any_empty = false;
-
-        for shape in self.iter_mut() {
-            if shape.is_simple() {
-                continue;
-            }
-            any_simplified = true;
-            if let Some(simple_shape) = shape.simplified() {
-                *shape = simple_shape;
-            } else {
-                shape.clear();
-                any_empty = true;
-            }
-        }
+        ContourCleaner::default().clean_shapes(self)
+    }
+}
 
-        if any_empty {
-            self.retain(|contour| !contour.is_empty());
-        }
+impl Simplify for FlatContoursBuffer {
+    /// Simplifies every contour in place, degenerate contours are removed.
+    #[inline]
+    fn simplify_contour(&mut self) -> bool {
+        ContourCleaner::default().clean_flat_contours(self)
+    }
+}
 
-        any_simplified
+impl Simplify for FlatShapesBuffer {
+    /// Simplifies every contour in place, degenerate holes are removed
+    /// and a shape is removed if its outer contour is degenerate.
+    #[inline]
+    fn simplify_contour(&mut self) -> bool {
+        ContourCleaner::default().clean_flat_shapes(self)
     }
 }
+
 impl SimpleShape for [IntContour] {
+    #[inline]
+    fn is_clean(&self) -> bool {
+        self.iter().all(|contour| contour.is_clean())
+    }
+
     #[inline]
     fn is_simple(&self) -> bool {
         for contour in self.iter() {
@@ -157,23 +158,23 @@ impl SimpleShape for [IntContour] {
         true
     }
 
+    #[inline]
     fn simplified(&self) -> Option<IntShape> {
-        let mut contours = Vec::with_capacity(self.len());
-        for (i, contour) in self.iter().enumerate() {
-            if contour.is_simple() {
-                contours.push(contour.clone());
-            } else if let Some(simple) = contour.simplified() {
-                contours.push(simple);
-            } else if i == 0 {
-                return None;
-            }
+        let mut shape = self.to_vec();
+        if ContourCleaner::default().clean_shape(&mut shape) && shape.is_empty() {
+            None
+        } else {
+            Some(shape)
         }
-
-        Some(contours)
     }
 }
 
 impl SimpleShapes for [IntShape] {
+    #[inline]
+    fn is_clean(&self) -> bool {
+        self.iter().all(|shape| shape.is_clean())
+    }
+
     #[inline]
     fn is_simple(&self) -> bool {
         for shape in self.iter() {
@@ -184,22 +185,16 @@ impl SimpleShapes for [IntShape] {
         true
     }
 
+    #[inline]
     fn simplified(&self) -> IntShapes {
-        let mut shapes = Vec::with_capacity(self.len());
-        for shape in self.iter() {
-            if shape.is_simple() {
-                shapes.push(shape.clone());
-            } else if let Some(simple) = shape.simplified() {
-                shapes.push(simple);
-            }
-        }
-
+        let mut shapes = self.to_vec();
+        ContourCleaner::default().clean_shapes(&mut shapes);
         shapes
     }
 }
 
 impl SimpleContour for [IntPoint] {
-    fn is_simple(&self) -> bool {
+    fn is_clean(&self) -> bool {
         let count = self.len();
 
         if count < 3 {
@@ -207,128 +202,45 @@ impl SimpleContour for [IntPoint] {
         }
 
         let mut p0 = self[count - 2];
-        let p1 = self[count - 1];
-
-        let mut v0 = p1.subtract(p0);
-        p0 = p1;
+        let mut p1 = self[count - 1];
 
-        for &pi in self.iter() {
-            let vi = pi.subtract(p0);
-            let prod = vi.cross_product(v0);
-            if prod == 0 {
+        for &p2 in self.iter() {
+            // also catches duplicates, the orientation of a zero length edge is zero
+            if orient(p0, p1, p2) == 0 {
                 return false;
             }
-            v0 = vi;
-            p0 = pi;
+            p0 = p1;
+            p1 = p2;
         }
 
         true
     }
 
+    #[inline]
+    fn is_simple(&self) -> bool {
+        self.is_clean() && !self.has_self_intersection()
+    }
+
     #[inline]
     fn simplified(&self) -> Option<IntContour> {
         ContourSimplifier::default().simplify_contour(self)
     }
 }
 
+/// A reusable simplifier, see `ContourCleaner` to also avoid the output allocation.
 #[derive(Default)]
 pub struct ContourSimplifier {
-    nodes: Vec<Node>,
-    validated: Vec<bool>,
+    cleaner: ContourCleaner,
 }
 
 impl ContourSimplifier {
+    #[inline]
     pub fn simplify_contour(&mut self, contour: &[IntPoint]) -> Option<IntContour> {
-        let mut n = contour.len();
-
-        if n < 3 {
-            return None;
-        }
-
-        self.validated.clear();
-        self.validated.resize(n, false);
-
-        self.nodes.clear();
-        self.nodes.reserve(n);
-
-        let mut prev = n - 1;
-        let mut next = 1;
-        let last = n - 1;
-        for index in 0..last {
-            self.nodes.push(Node { next, index, prev });
-            prev = index;
-            next += 1;
-        }
-        self.nodes.push(Node {
-            next: 0,
-            index: last,
-            prev,
-        });
-
-        let mut first: usize = 0;
-        let mut node = self.nodes[first];
-        let mut i = 0;
-        while i < n {
-            if self.validated[node.index] {
-                node = self.nodes[node.next];
-                continue;
-            }
-
-            let p0 = contour[node.prev];
-            let p1 = contour[node.index];
-            let p2 = contour[node.next];
-
-            if p1.subtract(p0).cross_product(p2.subtract(p1)) == 0 {
-                n -= 1;
-                if n < 3 {
-                    return None;
-                }
-
-                // remove node
-                self.nodes[node.prev].next = node.next;
-                self.nodes[node.next].prev = node.prev;
-
-                if node.index == first {
-                    first = node.next
-                }
-
-                node = self.nodes[node.prev];
-
-                if self.validated[node.prev] {
-                    i -= 1;
-                    self.validated[node.prev] = false
-                }
-
-                if self.validated[node.next] {
-                    i -= 1;
-                    self.validated[node.next] = false
-                }
-
-                if self.validated[node.index] {
-                    i -= 1;
-                    self.validated[node.index] = false
-                }
-            } else {
-                self.validated[node.index] = true;
-                i += 1;
-                node = self.nodes[node.next];
-            }
-        }
-
-        let mut buffer = vec![IntPoint::ZERO; n];
-        node = self.nodes[first];
-
-        for item in buffer.iter_mut().take(n) {
-            *item = contour[node.index];
-            node = self.nodes[node.next];
+        let mut buffer = Vec::new();
+        if self.cleaner.clean_into(contour, &mut buffer) {
+            Some(buffer)
+        } else {
+            None
         }
-
-        Some(buffer)
     }
 }
-#[derive(Clone, Copy)]
-struct Node {
-    next: usize,
-    index: usize,
-    prev: usize,
-}
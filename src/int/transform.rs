@@ -0,0 +1,346 @@
+use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+use crate::int::shape::{IntContour, IntShape, IntShapes};
+use alloc::vec::Vec;
+use core::ops::Range;
+use i_float::int::point::IntPoint;
+
+/// An exact integer transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntTransform {
+    Translate {
+        dx: i32,
+        dy: i32,
+    },
+    /// Counter-clockwise rotation by 90° around the origin.
+    Rotate90,
+    Rotate180,
+    /// Counter-clockwise rotation by 270° around the origin.
+    Rotate270,
+    /// Reflection across the x-axis, `y` becomes `-y`.
+    MirrorX,
+    /// Reflection across the y-axis, `x` becomes `-x`.
+    MirrorY,
+    /// Scaling around the origin, a negative factor for one axis is a reflection.
+    /// A zero factor collapses contours and is rejected.
+    Scale {
+        sx: i32,
+        sy: i32,
+    },
+}
+
+impl IntTransform {
+    /// Applies the transform to a point.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(IntPoint)` the transformed point.
+    /// - `None` if a coordinate does not fit into `i32` or a scale factor is zero.
+    #[inline]
+    pub fn apply(&self, p: IntPoint) -> Option<IntPoint> {
+        let (x, y) = match *self {
+            IntTransform::Translate { dx, dy } => (p.x.checked_add(dx)?, p.y.checked_add(dy)?),
+            IntTransform::Rotate90 => (p.y.checked_neg()?, p.x),
+            IntTransform::Rotate180 => (p.x.checked_neg()?, p.y.checked_neg()?),
+            IntTransform::Rotate270 => (p.y, p.x.checked_neg()?),
+            IntTransform::MirrorX => (p.x, p.y.checked_neg()?),
+            IntTransform::MirrorY => (p.x.checked_neg()?, p.y),
+            IntTransform::Scale { sx, sy } => {
+                if sx == 0 || sy == 0 {
+                    return None;
+                }
+                (p.x.checked_mul(sx)?, p.y.checked_mul(sy)?)
+            }
+        };
+        Some(IntPoint::new(x, y))
+    }
+
+    /// Checks if the transform flips the contour direction.
+    #[inline]
+    pub fn is_reflection(&self) -> bool {
+        match *self {
+            IntTransform::MirrorX | IntTransform::MirrorY => true,
+            IntTransform::Scale { sx, sy } => (sx < 0) != (sy < 0),
+            _ => false,
+        }
+    }
+}
+
+/// A trait for applying an `IntTransform`.
+///
+/// Contours are reversed after a reflection, so outer contours and holes keep their direction.
+pub trait Transform {
+    /// Transforms the structure in-place.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the structure was transformed.
+    /// - `false` if any coordinate overflows or a scale factor is zero, the structure is left unchanged.
+    fn transform(&mut self, transform: IntTransform) -> bool;
+
+    /// Returns a transformed copy, `None` if any coordinate overflows or a scale factor is zero.
+    fn transformed(&self, transform: IntTransform) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+impl Transform for IntContour {
+    #[inline]
+    fn transform(&mut self, transform: IntTransform) -> bool {
+        let points = if let Some(points) = transformed_points(self.iter(), transform) {
+            points
+        } else {
+            return false;
+        };
+        self.copy_from_slice(&points);
+        if transform.is_reflection() {
+            self.reverse();
+        }
+        true
+    }
+
+    #[inline]
+    fn transformed(&self, transform: IntTransform) -> Option<Self> {
+        let mut contour = self
+            .iter()
+            .map(|&p| transform.apply(p))
+            .collect::<Option<IntContour>>()?;
+        if transform.is_reflection() {
+            contour.reverse();
+        }
+        Some(contour)
+    }
+}
+
+impl Transform for IntShape {
+    #[inline]
+    fn transform(&mut self, transform: IntTransform) -> bool {
+        let points = if let Some(points) = transformed_points(self.iter().flatten(), transform) {
+            points
+        } else {
+            return false;
+        };
+        write(self.iter_mut(), &points, transform);
+        true
+    }
+
+    #[inline]
+    fn transformed(&self, transform: IntTransform) -> Option<Self> {
+        self.iter()
+            .map(|contour| contour.transformed(transform))
+            .collect()
+    }
+}
+
+impl Transform for IntShapes {
+    #[inline]
+    fn transform(&mut self, transform: IntTransform) -> bool {
+        let points = if let Some(points) = transformed_points(self.iter().flatten().flatten(), transform) {
+            points
+        } else {
+            return false;
+        };
+        write(self.iter_mut().flatten(), &points, transform);
+        true
+    }
+
+    #[inline]
+    fn transformed(&self, transform: IntTransform) -> Option<Self> {
+        self.iter().map(|shape| shape.transformed(transform)).collect()
+    }
+}
+
+impl Transform for FlatContoursBuffer {
+    #[inline]
+    fn transform(&mut self, transform: IntTransform) -> bool {
+        transform_flat(&mut self.points, &self.ranges, transform)
+    }
+
+    #[inline]
+    fn transformed(&self, transform: IntTransform) -> Option<Self> {
+        let mut buffer = self.clone();
+        if buffer.transform(transform) {
+            Some(buffer)
+        } else {
+            None
+        }
+    }
+}
+
+impl Transform for FlatShapesBuffer {
+    #[inline]
+    fn transform(&mut self, transform: IntTransform) -> bool {
+        transform_flat(&mut self.points, &self.contour_ranges, transform)
+    }
+
+    #[inline]
+    fn transformed(&self, transform: IntTransform) -> Option<Self> {
+        let mut buffer = self.clone();
+        if buffer.transform(transform) {
+            Some(buffer)
+        } else {
+            None
+        }
+    }
+}
+
+/// Transforms all points before anything is written, so a failure leaves the source unchanged.
+#[inline]
+fn transformed_points<'a, I>(points: I, transform: IntTransform) -> Option<Vec<IntPoint>>
+where
+    I: Iterator<Item = &'a IntPoint>,
+{
+    points.map(|&p| transform.apply(p)).collect()
+}
+
+/// Writes the transformed points back contour by contour.
+#[inline]
+fn write<'a, I>(contours: I, points: &[IntPoint], transform: IntTransform)
+where
+    I: Iterator<Item = &'a mut IntContour>,
+{
+    let mut offset = 0;
+    for contour in contours {
+        let end = offset + contour.len();
+        contour.copy_from_slice(&points[offset..end]);
+        if transform.is_reflection() {
+            contour.reverse();
+        }
+        offset = end;
+    }
+}
+
+/// Only the points covered by `ranges` are transformed, the rest of `points` is not geometry.
+#[inline]
+fn transform_flat(points: &mut [IntPoint], ranges: &[Range<usize>], transform: IntTransform) -> bool {
+    let covered = ranges.iter().flat_map(|range| points[range.clone()].iter());
+    let transformed = if let Some(transformed) = transformed_points(covered, transform) {
+        transformed
+    } else {
+        return false;
+    };
+
+    let mut offset = 0;
+    for range in ranges.iter() {
+        let end = offset + range.len();
+        let contour = &mut points[range.clone()];
+        contour.copy_from_slice(&transformed[offset..end]);
+        if transform.is_reflection() {
+            contour.reverse();
+        }
+        offset = end;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
+    use crate::int::area::ExactArea;
+    use crate::int::shape::IntShape;
+    use crate::int::transform::{IntTransform, Transform};
+    use crate::int::validate::Validate;
+    use crate::{int_path, int_shape};
+    use i_float::int::point::IntPoint;
+
+    fn shape() -> IntShape {
+        int_shape![
+            [[0, 0], [10, 0], [10, 10], [0, 10],],
+            [[2, 2], [2, 8], [8, 8], [8, 2],],
+        ]
+    }
+
+    #[test]
+    fn test_0() {
+        let contour = int_path![[0, 0], [2, 0], [2, 1],];
+
+        let t = IntTransform::Translate { dx: 1, dy: -1 };
+        assert_eq!(
+            contour.transformed(t).unwrap(),
+            int_path![[1, -1], [3, -1], [3, 0],]
+        );
+
+        let r = contour.transformed(IntTransform::Rotate90).unwrap();
+        assert_eq!(r, int_path![[0, 0], [0, 2], [-1, 2],]);
+        assert_eq!(r.transformed(IntTransform::Rotate270).unwrap(), contour);
+        assert_eq!(
+            contour.transformed(IntTransform::Rotate180).unwrap(),
+            int_path![[0, 0], [-2, 0], [-2, -1],]
+        );
+    }
+
+    #[test]
+    fn test_1() {
+        let shape = shape();
+        assert!(shape.validate().is_empty());
+
+        for t in [
+            IntTransform::MirrorX,
+            IntTransform::MirrorY,
+            IntTransform::Scale { sx: -2, sy: 3 },
+            IntTransform::Scale { sx: -1, sy: -1 },
+        ] {
+            let result = shape.transformed(t).unwrap();
+            assert!(result.validate().is_empty());
+            assert_eq!(result[0].exact_area_two() < 0, shape[0].exact_area_two() < 0);
+        }
+    }
+
+    #[test]
+    fn test_2() {
+        let mut shape = shape();
+        shape[0][1].x = i32::MAX;
+
+        let origin = shape.clone();
+        assert!(!shape.transform(IntTransform::Translate { dx: 1, dy: 0 }));
+        assert!(!shape.transform(IntTransform::Scale { sx: 2, sy: 1 }));
+        assert_eq!(shape, origin);
+        assert!(shape.transform(IntTransform::MirrorY));
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shape(&origin);
+        assert!(buffer.transform(IntTransform::MirrorY));
+        assert_eq!(buffer.to_shapes(), [shape]);
+
+        let min = int_path![[i32::MIN, 0], [0, 0], [0, 1],];
+        assert!(min.transformed(IntTransform::Rotate180).is_none());
+    }
+
+    #[test]
+    fn test_3() {
+        let mut shape = shape();
+        let origin = shape.clone();
+
+        for t in [
+            IntTransform::Scale { sx: 0, sy: 1 },
+            IntTransform::Scale { sx: -1, sy: 0 },
+            IntTransform::Scale { sx: 0, sy: 0 },
+        ] {
+            assert!(t.apply(IntPoint::new(1, 1)).is_none());
+            assert!(!shape.transform(t));
+            assert!(shape.transformed(t).is_none());
+        }
+        assert_eq!(shape, origin);
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shape(&origin);
+        assert!(!buffer.transform(IntTransform::Scale { sx: 0, sy: 2 }));
+        assert_eq!(buffer.to_shapes(), [origin]);
+    }
+
+    #[test]
+    fn test_4() {
+        // the last point is not covered by any range, it must not block the transform
+        let mut buffer = FlatContoursBuffer::default();
+        buffer.set_with_contour(&int_path![[0, 0], [2, 0], [2, 1],]);
+        buffer.points.push(IntPoint::new(i32::MAX, 0));
+
+        assert!(buffer.transform(IntTransform::Translate { dx: 1, dy: 0 }));
+        assert_eq!(buffer.points, int_path![[1, 0], [3, 0], [3, 1], [i32::MAX, 0],]);
+
+        assert!(buffer.transform(IntTransform::MirrorY));
+        assert_eq!(
+            buffer.points,
+            int_path![[-3, 1], [-3, 0], [-1, 0], [i32::MAX, 0],]
+        );
+    }
+}
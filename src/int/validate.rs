@@ -0,0 +1,379 @@
+use crate::base::direction::ContourDirection;
+use crate::flat::buffer::FlatShapesBuffer;
+use crate::int::area::ExactArea;
+use crate::int::exact::{dot, orient};
+use crate::int::intersection::{EdgePair, EdgeSweep};
+use crate::int::location::{ClassifyPoint, PointLocation};
+use crate::int::reverse::direction;
+use crate::int::shape::{IntContour, IntShape};
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_float::int::rect::IntRect;
+
+/// A problem found in a shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeIssue {
+    /// The index of the shape in the validated collection.
+    pub shape: usize,
+    /// The index of the contour inside the shape, `0` is the outer contour.
+    pub contour: usize,
+    pub kind: IssueKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The contour has fewer than 3 points.
+    TooFewPoints,
+    /// The vertex is equal to the previous one.
+    DuplicatePoint { vertex: usize },
+    /// The vertex lies on the straight line between its neighbors.
+    CollinearPoint { vertex: usize },
+    /// The contour turns back at the vertex along the same line.
+    Spike { vertex: usize },
+    /// Two edges of the contour cross or touch each other.
+    SelfIntersection { edges: EdgePair },
+    /// The contour direction does not match the expected one.
+    WrongOrientation,
+    /// The hole is not strictly inside the outer contour.
+    HoleOutside,
+    /// The hole crosses, touches or contains another hole.
+    HolesOverlap { other: usize },
+}
+
+/// A reusable validator for shapes.
+///
+/// The outer contour of every shape is expected to have `main_direction`
+/// and all holes are expected to have the reversed direction.
+#[derive(Default)]
+pub struct ShapeValidator {
+    pub main_direction: ContourDirection,
+    sweep: EdgeSweep,
+    contours: Vec<ContourState>,
+}
+
+pub trait Validate {
+    /// Validates the structure with a counter-clockwise outer contour direction.
+    ///
+    /// # Returns
+    ///
+    /// - A list of issues, empty if the structure is valid.
+    fn validate(&self) -> Vec<ShapeIssue>;
+}
+
+impl Validate for [IntContour] {
+    #[inline]
+    fn validate(&self) -> Vec<ShapeIssue> {
+        ShapeValidator::default().validate_shape(self)
+    }
+}
+
+impl Validate for [IntShape] {
+    #[inline]
+    fn validate(&self) -> Vec<ShapeIssue> {
+        ShapeValidator::default().validate_shapes(self)
+    }
+}
+
+impl Validate for FlatShapesBuffer {
+    #[inline]
+    fn validate(&self) -> Vec<ShapeIssue> {
+        ShapeValidator::default().validate_flat_shapes(self)
+    }
+}
+
+#[derive(Clone)]
+struct ContourState {
+    is_valid: bool,
+    rect: Option<IntRect>,
+}
+
+impl ShapeValidator {
+    #[inline]
+    pub fn with_direction(main_direction: ContourDirection) -> Self {
+        Self {
+            main_direction,
+            ..Default::default()
+        }
+    }
+
+    pub fn validate_shape(&mut self, shape: &[IntContour]) -> Vec<ShapeIssue> {
+        let mut issues = Vec::new();
+        let contours: Vec<&[IntPoint]> = shape.iter().map(|contour| contour.as_slice()).collect();
+        self.validate_contours(0, &contours, &mut issues);
+        issues
+    }
+
+    pub fn validate_shapes(&mut self, shapes: &[IntShape]) -> Vec<ShapeIssue> {
+        let mut issues = Vec::new();
+        let mut contours = Vec::new();
+        for (index, shape) in shapes.iter().enumerate() {
+            contours.clear();
+            contours.extend(shape.iter().map(|contour| contour.as_slice()));
+            self.validate_contours(index, &contours, &mut issues);
+        }
+        issues
+    }
+
+    pub fn validate_flat_shapes(&mut self, buffer: &FlatShapesBuffer) -> Vec<ShapeIssue> {
+        let mut issues = Vec::new();
+        let mut contours = Vec::new();
+        for (index, shape_range) in buffer.shape_ranges.iter().enumerate() {
+            contours.clear();
+            contours.extend(
+                buffer.contour_ranges[shape_range.clone()]
+                    .iter()
+                    .map(|range| &buffer.points[range.clone()]),
+            );
+            self.validate_contours(index, &contours, &mut issues);
+        }
+        issues
+    }
+
+    fn validate_contours(&mut self, shape: usize, contours: &[&[IntPoint]], issues: &mut Vec<ShapeIssue>) {
+        self.contours.clear();
+        for (index, &contour) in contours.iter().enumerate() {
+            let direction = direction(index, self.main_direction);
+            let is_valid = self.validate_contour(shape, index, contour, direction, issues);
+            self.contours.push(ContourState {
+                is_valid,
+                rect: IntRect::with_points(contour),
+            });
+        }
+
+        self.validate_holes(shape, contours, issues);
+    }
+
+    /// Returns `true` if the contour can be used in the holes tests.
+    fn validate_contour(
+        &mut self,
+        shape: usize,
+        contour: usize,
+        points: &[IntPoint],
+        direction: ContourDirection,
+        issues: &mut Vec<ShapeIssue>,
+    ) -> bool {
+        let issue = |kind| ShapeIssue { shape, contour, kind };
+
+        if points.len() < 3 {
+            issues.push(issue(IssueKind::TooFewPoints));
+            return false;
+        }
+
+        let mut is_degenerate = false;
+        let n = points.len();
+        let mut p0 = points[n - 1];
+        for (vertex, &p1) in points.iter().enumerate() {
+            let p2 = points[(vertex + 1) % n];
+            if p1 == p0 {
+                issues.push(issue(IssueKind::DuplicatePoint { vertex }));
+                is_degenerate = true;
+            } else if p2 != p1 && orient(p0, p1, p2) == 0 {
+                if dot(p1, p0, p2) > 0 {
+                    issues.push(issue(IssueKind::Spike { vertex }));
+                    is_degenerate = true;
+                } else {
+                    issues.push(issue(IssueKind::CollinearPoint { vertex }));
+                }
+            }
+            p0 = p1;
+        }
+
+        let area = points.exact_area_two();
+        let is_clockwise = area > 0;
+        let expected_clockwise = direction == ContourDirection::Clockwise;
+        if area != 0 && is_clockwise != expected_clockwise {
+            issues.push(issue(IssueKind::WrongOrientation));
+        }
+
+        if is_degenerate {
+            // duplicates and spikes are intersections by themselves
+            return false;
+        }
+
+        if let Some((e0, e1)) = self.sweep.find(&[points]) {
+            issues.push(issue(IssueKind::SelfIntersection {
+                edges: EdgePair {
+                    a: e0.edge,
+                    b: e1.edge,
+                },
+            }));
+            return false;
+        }
+
+        true
+    }
+
+    fn validate_holes(&mut self, shape: usize, contours: &[&[IntPoint]], issues: &mut Vec<ShapeIssue>) {
+        if contours.len() < 2 {
+            return;
+        }
+
+        let outer = &self.contours[0];
+        if outer.is_valid {
+            let outer_rect = outer.rect.clone();
+            for hole in 1..contours.len() {
+                if !self.contours[hole].is_valid {
+                    continue;
+                }
+                let is_inside_rect = match (&outer_rect, &self.contours[hole].rect) {
+                    (Some(outer_rect), Some(hole_rect)) => outer_rect.contains_rect(hole_rect),
+                    _ => false,
+                };
+
+                let is_outside = !is_inside_rect
+                    || self.sweep.find(&[contours[0], contours[hole]]).is_some()
+                    || contours[0].classify_point(contours[hole][0]) != PointLocation::Inside;
+
+                if is_outside {
+                    issues.push(ShapeIssue {
+                        shape,
+                        contour: hole,
+                        kind: IssueKind::HoleOutside,
+                    });
+                }
+            }
+        }
+
+        for i in 1..contours.len() {
+            if !self.contours[i].is_valid {
+                continue;
+            }
+            for j in i + 1..contours.len() {
+                if !self.contours[j].is_valid {
+                    continue;
+                }
+                let is_rect_overlap = match (&self.contours[i].rect, &self.contours[j].rect) {
+                    (Some(r0), Some(r1)) => r0.is_intersect_border_include(r1),
+                    _ => false,
+                };
+                if !is_rect_overlap {
+                    continue;
+                }
+
+                let is_overlap = self.sweep.find(&[contours[i], contours[j]]).is_some()
+                    || contours[i].classify_point(contours[j][0]) == PointLocation::Inside
+                    || contours[j].classify_point(contours[i][0]) == PointLocation::Inside;
+
+                if is_overlap {
+                    issues.push(ShapeIssue {
+                        shape,
+                        contour: i,
+                        kind: IssueKind::HolesOverlap { other: j },
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base::direction::ContourDirection;
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::int::intersection::EdgePair;
+    use crate::int::validate::{IssueKind, ShapeIssue, ShapeValidator, Validate};
+    use crate::{int_shape, int_shapes};
+
+    fn kinds(issues: &[ShapeIssue]) -> alloc::vec::Vec<(usize, IssueKind)> {
+        issues.iter().map(|issue| (issue.contour, issue.kind)).collect()
+    }
+
+    #[test]
+    fn test_0() {
+        let shape = int_shape![
+            [[-10, -10], [10, -10], [10, 10], [-10, 10],],
+            [[-5, -5], [-5, 5], [5, 5], [5, -5],],
+        ];
+
+        assert!(shape.validate().is_empty());
+
+        let issues = ShapeValidator::with_direction(ContourDirection::Clockwise).validate_shape(&shape);
+        assert_eq!(
+            kinds(&issues),
+            [(0, IssueKind::WrongOrientation), (1, IssueKind::WrongOrientation)]
+        );
+    }
+
+    #[test]
+    fn test_1() {
+        let shape = int_shape![
+            [[0, 0], [5, 0], [10, 0], [10, 0], [10, 10], [0, 10],],
+            [[1, 1], [1, 2],],
+        ];
+
+        let issues = shape.validate();
+        assert_eq!(
+            kinds(&issues),
+            [
+                (0, IssueKind::CollinearPoint { vertex: 1 }),
+                (0, IssueKind::DuplicatePoint { vertex: 3 }),
+                (1, IssueKind::TooFewPoints),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        let shape = int_shape![
+            [[0, 0], [10, 0], [10, 10], [0, 10], [0, 15], [0, 5],],
+            [[0, 0], [10, 10], [10, 0], [0, 10],],
+        ];
+
+        let issues = shape.validate();
+        assert_eq!(
+            kinds(&issues),
+            [
+                (0, IssueKind::Spike { vertex: 4 }),
+                (0, IssueKind::CollinearPoint { vertex: 5 }),
+                (
+                    1,
+                    IssueKind::SelfIntersection {
+                        edges: EdgePair { a: 0, b: 2 }
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_3() {
+        let shapes = int_shapes![
+            [
+                [[-10, -10], [10, -10], [10, 10], [-10, 10],],
+                [[-5, -5], [-5, 5], [5, 5], [5, -5],],
+                [[-2, -2], [-2, 2], [2, 2], [2, -2],],
+                [[20, 20], [20, 21], [21, 21], [21, 20],],
+            ],
+            [
+                [[-10, -10], [10, -10], [10, 10], [-10, 10],],
+                [[-5, -5], [-5, 15], [5, 15], [5, -5],],
+            ],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+
+        let issues = buffer.validate();
+        assert_eq!(issues, shapes.validate());
+        assert_eq!(
+            issues,
+            [
+                ShapeIssue {
+                    shape: 0,
+                    contour: 3,
+                    kind: IssueKind::HoleOutside
+                },
+                ShapeIssue {
+                    shape: 0,
+                    contour: 1,
+                    kind: IssueKind::HolesOverlap { other: 2 }
+                },
+                ShapeIssue {
+                    shape: 1,
+                    contour: 1,
+                    kind: IssueKind::HoleOutside
+                },
+            ]
+        );
+    }
+}
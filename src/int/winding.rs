@@ -0,0 +1,208 @@
+use crate::int::exact::{is_on_segment, orient};
+use crate::int::shape::{IntContour, IntShape};
+use i_float::int::point::IntPoint;
+
+/// Rules that decide whether a point is filled from its winding number.
+///
+/// The rules are the same as in iOverlay, the conventions they are applied with are:
+/// - A counter-clockwise contour adds `+1` and a clockwise contour adds `-1`,
+///   so `Positive` fills the regions of counter-clockwise contours, the default `ContourDirection`
+///   and the direction iOverlay uses for outer contours.
+///   Note that the sign is the opposite of `Area`, which is positive for clockwise contours.
+/// - Points lying exactly on an edge are filled under every rule,
+///   so a filled region is closed and matches `PointLocation::OnBoundary` of `ClassifyPoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Filled if the winding number is odd.
+    EvenOdd,
+    /// Filled if the winding number is not zero.
+    NonZero,
+    /// Filled if the winding number is positive.
+    Positive,
+    /// Filled if the winding number is negative.
+    Negative,
+}
+
+impl FillRule {
+    #[inline]
+    pub fn is_filled(self, winding: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding & 1 == 1,
+            FillRule::NonZero => winding != 0,
+            FillRule::Positive => winding > 0,
+            FillRule::Negative => winding < 0,
+        }
+    }
+}
+
+pub trait WindingNumber {
+    /// The signed number of times the contours wind around the point.
+    ///
+    /// Every counter-clockwise contour adds `+1` and every clockwise contour adds `-1`
+    /// if it encloses the point. Points lying exactly on an edge are resolved with a half-open rule,
+    /// use `is_filled` or `ClassifyPoint` if they must be detected.
+    fn winding_number(&self, point: IntPoint) -> i32;
+
+    /// Checks if the point is filled under the given fill rule.
+    /// Points lying exactly on any edge are always considered filled, see `FillRule`.
+    fn is_filled(&self, point: IntPoint, fill_rule: FillRule) -> bool;
+}
+
+impl WindingNumber for [IntPoint] {
+    #[inline]
+    fn winding_number(&self, point: IntPoint) -> i32 {
+        contour_winding(self, point).0
+    }
+
+    #[inline]
+    fn is_filled(&self, point: IntPoint, fill_rule: FillRule) -> bool {
+        let (winding, on_boundary) = contour_winding(self, point);
+        on_boundary || fill_rule.is_filled(winding)
+    }
+}
+
+impl WindingNumber for [IntContour] {
+    #[inline]
+    fn winding_number(&self, point: IntPoint) -> i32 {
+        self.iter().map(|contour| contour_winding(contour, point).0).sum()
+    }
+
+    #[inline]
+    fn is_filled(&self, point: IntPoint, fill_rule: FillRule) -> bool {
+        contours_filled(self.iter().map(|contour| contour.as_slice()), point, fill_rule)
+    }
+}
+
+impl WindingNumber for [IntShape] {
+    #[inline]
+    fn winding_number(&self, point: IntPoint) -> i32 {
+        self.iter().map(|shape| shape.winding_number(point)).sum()
+    }
+
+    #[inline]
+    fn is_filled(&self, point: IntPoint, fill_rule: FillRule) -> bool {
+        contours_filled(
+            self.iter().flatten().map(|contour| contour.as_slice()),
+            point,
+            fill_rule,
+        )
+    }
+}
+
+/// Sums the winding numbers of the contours, a point on any boundary is filled.
+fn contours_filled<'a, I: Iterator<Item = &'a [IntPoint]>>(
+    contours: I,
+    point: IntPoint,
+    fill_rule: FillRule,
+) -> bool {
+    let mut winding = 0;
+    for contour in contours {
+        let (contour_winding, on_boundary) = contour_winding(contour, point);
+        if on_boundary {
+            return true;
+        }
+        winding += contour_winding;
+    }
+
+    fill_rule.is_filled(winding)
+}
+
+/// Returns the winding number of a single contour and whether the point lies on its boundary.
+fn contour_winding(contour: &[IntPoint], point: IntPoint) -> (i32, bool) {
+    let mut a = if let Some(&p) = contour.last() {
+        p
+    } else {
+        return (0, false);
+    };
+
+    let mut winding = 0;
+    let mut on_boundary = false;
+    for &b in contour.iter() {
+        on_boundary |= is_on_segment(point, a, b);
+        if a.y <= point.y {
+            if b.y > point.y && orient(a, b, point) > 0 {
+                // upward crossing with the point on the left
+                winding += 1;
+            }
+        } else if b.y <= point.y && orient(a, b, point) < 0 {
+            // downward crossing with the point on the right
+            winding -= 1;
+        }
+        a = b;
+    }
+
+    (winding, on_boundary)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int::winding::{FillRule, WindingNumber};
+    use crate::{int_path, int_shape, int_shapes};
+    use i_float::int::point::IntPoint;
+
+    #[test]
+    fn test_0() {
+        let ccw = int_path![[0, 0], [10, 0], [10, 10], [0, 10],];
+        let cw = int_path![[0, 0], [0, 10], [10, 10], [10, 0],];
+
+        assert_eq!(ccw.winding_number(IntPoint::new(5, 5)), 1);
+        assert_eq!(cw.winding_number(IntPoint::new(5, 5)), -1);
+        assert_eq!(ccw.winding_number(IntPoint::new(15, 5)), 0);
+    }
+
+    #[test]
+    fn test_1() {
+        // two overlapped counter-clockwise squares
+        let shape = int_shape![
+            [[0, 0], [10, 0], [10, 10], [0, 10],],
+            [[5, 5], [15, 5], [15, 15], [5, 15],],
+        ];
+
+        let p = IntPoint::new(7, 7);
+        assert_eq!(shape.winding_number(p), 2);
+        assert!(!shape.is_filled(p, FillRule::EvenOdd));
+        assert!(shape.is_filled(p, FillRule::NonZero));
+        assert!(shape.is_filled(p, FillRule::Positive));
+        assert!(!shape.is_filled(p, FillRule::Negative));
+
+        let q = IntPoint::new(2, 2);
+        assert_eq!(shape.winding_number(q), 1);
+        assert!(shape.is_filled(q, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn test_2() {
+        // outer is counter-clockwise, hole is clockwise
+        let shapes = int_shapes![[
+            [[-10, -10], [10, -10], [10, 10], [-10, 10],],
+            [[-5, -5], [-5, 5], [5, 5], [5, -5],],
+        ],];
+
+        assert_eq!(shapes.winding_number(IntPoint::new(0, 0)), 0);
+        assert_eq!(shapes.winding_number(IntPoint::new(7, 0)), 1);
+        assert!(!shapes.is_filled(IntPoint::new(0, 0), FillRule::NonZero));
+        assert!(shapes.is_filled(IntPoint::new(5, 0), FillRule::NonZero));
+        assert!(!shapes.is_filled(IntPoint::new(7, 0), FillRule::Negative));
+    }
+
+    #[test]
+    fn test_3() {
+        let ccw = int_shape![[[0, 0], [10, 0], [10, 10], [0, 10],],];
+        let inside = IntPoint::new(5, 5);
+        let edge = IntPoint::new(10, 5);
+
+        // counter-clockwise contours are positive
+        assert!(ccw.is_filled(inside, FillRule::Positive));
+        assert!(!ccw.is_filled(inside, FillRule::Negative));
+
+        // a boundary point is filled under every rule
+        for rule in [
+            FillRule::EvenOdd,
+            FillRule::NonZero,
+            FillRule::Positive,
+            FillRule::Negative,
+        ] {
+            assert!(ccw.is_filled(edge, rule));
+        }
+    }
+}
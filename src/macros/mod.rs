@@ -1,81 +1,57 @@
 #[macro_export]
 macro_rules! int_path {
     ( $( [$x:expr, $y:expr] ),* $(,)? ) => {
-        {
-            let mut path: $crate::int::path::IntPath = ::core::default::Default::default();
-            $(
-                path.push($crate::int::IntPoint::new($x, $y));
-            )*
-            path
-        }
+        <$crate::int::path::IntPath as ::core::iter::FromIterator<_>>::from_iter([
+            $( $crate::int::IntPoint::new($x, $y) ),*
+        ])
     };
 }
 
 #[macro_export]
 macro_rules! int_shape {
     ( $( [ $( [$x:expr, $y:expr] ),* $(,)? ] ),* $(,)? ) => {
-        {
-            let mut shape: $crate::int::shape::IntShape = ::core::default::Default::default();
+        <$crate::int::shape::IntShape as ::core::iter::FromIterator<_>>::from_iter([
             $(
-                let mut contour: $crate::int::shape::IntContour = ::core::default::Default::default();
-                $(
-                    contour.push($crate::int::IntPoint::new($x, $y));
-                )*
-                shape.push(contour);
-            )*
-            shape
-        }
+                <$crate::int::shape::IntContour as ::core::iter::FromIterator<_>>::from_iter([
+                    $( $crate::int::IntPoint::new($x, $y) ),*
+                ])
+            ),*
+        ])
     };
 }
 
 #[macro_export]
 macro_rules! int_shapes {
     ( $( [ $( [ $( [$x:expr, $y:expr] ),* $(,)? ] ),* $(,)? ] ),* $(,)? ) => {
-        {
-            let mut shapes: $crate::int::shape::IntShapes = ::core::default::Default::default();
+        <$crate::int::shape::IntShapes as ::core::iter::FromIterator<_>>::from_iter([
             $(
-                let mut shape: $crate::int::shape::IntShape = ::core::default::Default::default();
-                $(
-                    let mut contour: $crate::int::shape::IntContour = ::core::default::Default::default();
+                <$crate::int::shape::IntShape as ::core::iter::FromIterator<_>>::from_iter([
                     $(
-                        contour.push($crate::int::IntPoint::new($x, $y));
-                    )*
-                    shape.push(contour);
-                )*
-                shapes.push(shape);
-            )*
-            shapes
-        }
+                        <$crate::int::shape::IntContour as ::core::iter::FromIterator<_>>::from_iter([
+                            $( $crate::int::IntPoint::new($x, $y) ),*
+                        ])
+                    ),*
+                ])
+            ),*
+        ])
     };
 }
 
 #[macro_export]
 macro_rules! path {
     ( $( $point:expr ),* $(,)? ) => {
-        {
-            let mut path: $crate::base::data::Path<_> = ::core::default::Default::default();
-            $(
-                path.push($point);
-            )*
-            path
-        }
+        <$crate::base::data::Path<_> as ::core::iter::FromIterator<_>>::from_iter([ $( $point ),* ])
     };
 }
 
 #[macro_export]
 macro_rules! paths {
     ( $( [ $( $point:expr ),* $(,)? ] ),* $(,)? ) => {
-        {
-            let mut paths: $crate::base::data::Paths<_> = ::core::default::Default::default();
+        <$crate::base::data::Paths<_> as ::core::iter::FromIterator<_>>::from_iter([
             $(
-                let mut path: $crate::base::data::Path<_> = ::core::default::Default::default();
-                $(
-                    path.push($point);
-                )*
-                paths.push(path);
-            )*
-            paths
-        }
+                <$crate::base::data::Path<_> as ::core::iter::FromIterator<_>>::from_iter([ $( $point ),* ])
+            ),*
+        ])
     };
 }
 
@@ -0,0 +1,212 @@
+use crate::source::resource::ShapeResource;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_float::float::rect::FloatRect;
+
+/// Builds one `FloatPointAdapter` shared by several resources.
+///
+/// The bounds of all added resources are merged, expanded by the margin,
+/// and the scale is either fixed or derived from the bounds.
+#[derive(Debug, Clone)]
+pub struct AdapterBuilder<P: FloatPointCompatible> {
+    rect: Option<FloatRect<P::Scalar>>,
+    margin: P::Scalar,
+    scale: Option<f64>,
+}
+
+impl<P: FloatPointCompatible> Default for AdapterBuilder<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: FloatPointCompatible> AdapterBuilder<P> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            rect: None,
+            margin: P::Scalar::from_float(0.0),
+            scale: None,
+        }
+    }
+
+    /// Includes all points of `resource` in the bounds.
+    #[inline]
+    pub fn add_resource<R>(&mut self, resource: &R) -> &mut Self
+    where
+        R: ShapeResource<P> + ?Sized,
+    {
+        for path in resource.iter_paths() {
+            self.add_points(path);
+        }
+        self
+    }
+
+    /// Includes `points` in the bounds.
+    #[inline]
+    pub fn add_points(&mut self, points: &[P]) -> &mut Self {
+        if let Some(rect) = FloatRect::with_iter(points.iter()) {
+            self.add_rect(&rect);
+        }
+        self
+    }
+
+    /// Includes `rect` in the bounds.
+    #[inline]
+    pub fn add_rect(&mut self, rect: &FloatRect<P::Scalar>) -> &mut Self {
+        self.rect = FloatRect::with_optional_rects(self.rect.as_ref(), Some(rect));
+        self
+    }
+
+    /// Expands the bounds by `margin` on every side.
+    #[inline]
+    pub fn margin(&mut self, margin: P::Scalar) -> &mut Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Uses a fixed float to int `scale` instead of the one derived from the bounds.
+    ///
+    /// The scaled bounds must fit into `i32`, use `try_build` to check it.
+    #[inline]
+    pub fn scale(&mut self, scale: f64) -> &mut Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// The merged bounds expanded by the margin, `None` if no points were added.
+    #[inline]
+    pub fn rect(&self) -> Option<FloatRect<P::Scalar>> {
+        let mut rect = self.rect.clone()?;
+        rect.add_offset(self.margin);
+        Some(rect)
+    }
+
+    /// Builds the adapter without checking the scale.
+    ///
+    /// # Returns
+    ///
+    /// - The adapter covering the bounds, the one for a zero rect if no points were added.
+    #[inline]
+    pub fn build(&self) -> FloatPointAdapter<P> {
+        let rect = self.rect().unwrap_or(FloatRect::zero());
+        if let Some(scale) = self.scale {
+            with_fixed_scale(rect, scale)
+        } else {
+            FloatPointAdapter::new(rect)
+        }
+    }
+
+    /// Builds the adapter if every point of the bounds can be converted.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FloatPointAdapter)` the same adapter as `build`.
+    /// - `None` if the fixed scale is not positive and finite, the bounds are not finite
+    ///   or inverted by a negative margin, or the scaled bounds do not fit into `i32`.
+    pub fn try_build(&self) -> Option<FloatPointAdapter<P>> {
+        let rect = self.rect().unwrap_or(FloatRect::zero());
+        let width = rect.width().to_f64();
+        let height = rect.height().to_f64();
+        if !(width >= 0.0 && height >= 0.0 && width.is_finite() && height.is_finite()) {
+            return None;
+        }
+
+        let scale = if let Some(scale) = self.scale {
+            scale
+        } else {
+            // the derived scale always fits
+            return Some(FloatPointAdapter::new(rect));
+        };
+
+        if !(scale > 0.0 && scale.is_finite()) {
+            return None;
+        }
+
+        // the points are converted relative to the rect center
+        let half_size = 0.5 * width.max(height) * scale;
+        if half_size > i32::MAX as f64 {
+            return None;
+        }
+
+        Some(with_fixed_scale(rect, scale))
+    }
+}
+
+/// Unlike `FloatPointAdapter::with_scale`, keeps the scale for a degenerate rect.
+#[inline]
+fn with_fixed_scale<P: FloatPointCompatible>(rect: FloatRect<P::Scalar>, scale: f64) -> FloatPointAdapter<P> {
+    let half = P::Scalar::from_float(0.5);
+    let offset = P::from_xy(
+        rect.min_x + rect.width() * half,
+        rect.min_y + rect.height() * half,
+    );
+    FloatPointAdapter {
+        dir_scale: P::Scalar::from_float(scale),
+        inv_scale: P::Scalar::from_float(1.0 / scale),
+        offset,
+        rect,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::adapter::AdapterBuilder;
+    use crate::{path, paths};
+
+    #[test]
+    fn test_0() {
+        let a = paths![[[0.0f64, 0.0], [2.0, 0.0], [2.0, 2.0]]];
+        let b = path![[5.0, -1.0], [6.0, 1.0], [5.0, 1.0]];
+
+        let adapter = AdapterBuilder::new()
+            .add_resource(&a)
+            .add_resource(&b)
+            .margin(1.0)
+            .build();
+
+        assert_eq!(adapter.rect.min_x, -1.0);
+        assert_eq!(adapter.rect.max_x, 7.0);
+        assert_eq!(adapter.rect.min_y, -2.0);
+        assert_eq!(adapter.rect.max_y, 3.0);
+
+        let adapter = AdapterBuilder::new().add_resource(&b).scale(1024.0).build();
+        assert_eq!(adapter.dir_scale, 1024.0);
+
+        let empty = AdapterBuilder::<[f64; 2]>::new();
+        assert!(empty.rect().is_none());
+        assert_eq!(empty.build().rect.max_x, 0.0);
+    }
+
+    #[test]
+    fn test_1() {
+        let point = path![[3.0f64, 4.0]];
+
+        // a single point keeps the requested scale
+        let adapter = AdapterBuilder::new()
+            .add_resource(&point)
+            .scale(8.0)
+            .try_build()
+            .unwrap();
+        assert_eq!(adapter.dir_scale, 8.0);
+        assert_eq!(adapter.float_to_int(&[3.0, 4.0]).x, 0);
+
+        let mut builder = AdapterBuilder::new();
+        builder.add_resource(&point);
+        for scale in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(builder.scale(scale).try_build().is_none());
+        }
+
+        let wide = path![[-1e6f64, 0.0], [1e6, 0.0], [0.0, 1.0]];
+        let mut builder = AdapterBuilder::new();
+        builder.add_resource(&wide);
+        assert!(builder.scale(1000.0).try_build().is_some());
+        assert!(builder.scale(10_000.0).try_build().is_none());
+
+        builder.scale(1.0).margin(-2e6);
+        assert!(builder.try_build().is_none());
+        assert!(AdapterBuilder::<[f64; 2]>::new().try_build().is_some());
+    }
+}
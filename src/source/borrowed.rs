@@ -0,0 +1,186 @@
+use crate::source::contour::ContourResourceIterator;
+use crate::source::resource::ShapeResource;
+use crate::source::shape::ShapeResourceIterator;
+use crate::source::shapes::ShapesResourceIterator;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use i_float::float::compatible::FloatPointCompatible;
+
+/// Iterates over a list of borrowed contours like `&[P]` or `Cow<[P]>`.
+pub struct BorrowedResourceIterator<'a, P, C> {
+    slice: &'a [C],
+    index: usize,
+    _point: PhantomData<P>,
+}
+
+impl<'a, P, C> BorrowedResourceIterator<'a, P, C> {
+    #[inline]
+    fn with_slice(slice: &'a [C]) -> Self {
+        Self {
+            slice,
+            index: 0,
+            _point: PhantomData,
+        }
+    }
+}
+
+impl<'a, P: 'a, C: AsRef<[P]>> Iterator for BorrowedResourceIterator<'a, P, C> {
+    type Item = &'a [P];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let it = self.slice.get(self.index)?;
+        self.index += 1;
+        Some(it.as_ref())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, P: 'a, C: AsRef<[P]>> ExactSizeIterator for BorrowedResourceIterator<'a, P, C> {}
+
+macro_rules! impl_borrowed_resource {
+    ($($ty:ty => $item:ty),+ $(,)?) => {$(
+        impl<'b, P> ShapeResource<P> for $ty
+        where
+            P: FloatPointCompatible,
+        {
+            type ResourceIter<'a>
+                = BorrowedResourceIterator<'a, P, $item>
+            where
+                P: 'a,
+                Self: 'a;
+
+            #[inline]
+            fn iter_paths(&self) -> Self::ResourceIter<'_> {
+                BorrowedResourceIterator::with_slice(self)
+            }
+        }
+    )+};
+}
+
+impl_borrowed_resource!(
+    [&'b [P]] => &'b [P],
+    &'b [&'b [P]] => &'b [P],
+    Vec<&'b [P]> => &'b [P],
+    [Cow<'b, [P]>] => Cow<'b, [P]>,
+    Vec<Cow<'b, [P]>> => Cow<'b, [P]>,
+);
+
+impl<'b, P, const N: usize> ShapeResource<P> for [&'b [P]; N]
+where
+    P: FloatPointCompatible,
+{
+    type ResourceIter<'a>
+        = BorrowedResourceIterator<'a, P, &'b [P]>
+    where
+        P: 'a,
+        Self: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        BorrowedResourceIterator::with_slice(self)
+    }
+}
+
+impl<P> ShapeResource<P> for Cow<'_, [P]>
+where
+    P: FloatPointCompatible,
+{
+    type ResourceIter<'a>
+        = ContourResourceIterator<'a, P>
+    where
+        P: 'a,
+        Self: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        ContourResourceIterator::with_slice(self)
+    }
+}
+
+impl<P> ShapeResource<P> for Cow<'_, [Vec<P>]>
+where
+    P: FloatPointCompatible,
+{
+    type ResourceIter<'a>
+        = ShapeResourceIterator<'a, P>
+    where
+        P: 'a,
+        Self: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        ShapeResourceIterator::with_slice(self)
+    }
+}
+
+impl<P> ShapeResource<P> for Cow<'_, [Vec<Vec<P>>]>
+where
+    P: FloatPointCompatible,
+{
+    type ResourceIter<'a>
+        = ShapesResourceIterator<'a, P>
+    where
+        P: 'a,
+        Self: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        ShapesResourceIterator::with_slice(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::FlatShapesBuffer;
+    use crate::source::resource::ShapeResource;
+    use alloc::borrow::Cow;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_resource_borrowed_slices() {
+        let a = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
+        let b = vec![[2.0, 2.0], [3.0, 2.0]];
+
+        let slices: Vec<&[[f64; 2]]> = vec![&a, &b];
+        let count = slices.iter_paths().fold(0, |s, it| s + it.len());
+        assert_eq!(count, 5);
+        assert_eq!(slices.as_slice().iter_paths().count(), 2);
+
+        let array: [&[[f64; 2]]; 2] = [&a, &b];
+        assert_eq!(array.iter_paths().nth(1), Some(b.as_slice()));
+
+        let mut iter = array.iter_paths();
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        iter.next();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_resource_cow() {
+        let a = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]];
+        let contours: Vec<Cow<[[f64; 2]]>> = vec![
+            Cow::Borrowed(&a),
+            Cow::Owned(vec![[2.0, 2.0], [3.0, 2.0], [3.0, 3.0]]),
+        ];
+        assert_eq!(contours.iter_paths().count(), 2);
+
+        let shape: Cow<[Vec<[f64; 2]>]> = Cow::Owned(vec![a.to_vec()]);
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_resource(&shape);
+        assert_eq!(buffer.contour_ranges, vec![0..3]);
+
+        let contour: Cow<[[f64; 2]]> = Cow::Borrowed(&a);
+        assert_eq!(contour.iter_paths().next(), Some(a.as_slice()));
+    }
+}
@@ -147,6 +147,7 @@ mod tests {
             [11.0, 11.0],
         ];
         let contour_ranges: [Range<usize>; 2] = [0..3, 3..6];
+        #[allow(clippy::single_range_in_vec_init)]
         let shape_ranges: [Range<usize>; 1] = [0..2];
         buffer.set_flat(&points, &contour_ranges, &shape_ranges);
 
@@ -9,7 +9,7 @@ pub struct ContourResourceIterator<'a, P> {
 
 impl<'a, P> ContourResourceIterator<'a, P> {
     #[inline]
-    fn with_slice(slice: &'a [P]) -> Self {
+    pub(crate) fn with_slice(slice: &'a [P]) -> Self {
         Self {
             slice,
             finished: false,
@@ -0,0 +1,110 @@
+use crate::source::resource::ShapeResource;
+use core::marker::PhantomData;
+use i_float::float::compatible::FloatPointCompatible;
+
+/// A `ShapeResource` over any cloneable iterator of borrowed contours.
+///
+/// The iterator is cloned on every `iter_paths` call, so it must be cheap to clone
+/// and must yield the same contours each time.
+#[derive(Debug, Clone, Copy)]
+pub struct IterResource<'c, I> {
+    iter: I,
+    _lifetime: PhantomData<&'c ()>,
+}
+
+impl<I> IterResource<'_, I> {
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+pub struct IterResourceIterator<'a, I> {
+    iter: I,
+    _lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a, 'c: 'a, P: 'c, I> Iterator for IterResourceIterator<'a, I>
+where
+    I: Iterator<Item = &'c [P]>,
+{
+    type Item = &'a [P];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'c, P, I> ShapeResource<P> for IterResource<'c, I>
+where
+    P: FloatPointCompatible + 'c,
+    I: Iterator<Item = &'c [P]> + Clone,
+{
+    type ResourceIter<'a>
+        = IterResourceIterator<'a, I>
+    where
+        P: 'a,
+        Self: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        IterResourceIterator {
+            iter: self.iter.clone(),
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat::buffer::FlatContoursBuffer;
+    use crate::source::iter::IterResource;
+    use crate::source::resource::ShapeResource;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    struct Node {
+        outline: Vec<[f64; 2]>,
+        visible: bool,
+    }
+
+    #[test]
+    fn test_resource_iter() {
+        let nodes = [
+            Node {
+                outline: vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0]],
+                visible: true,
+            },
+            Node {
+                outline: vec![[8.0, 8.0], [9.0, 8.0], [9.0, 9.0]],
+                visible: false,
+            },
+            Node {
+                outline: vec![[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]],
+                visible: true,
+            },
+        ];
+
+        let resource = IterResource::new(
+            nodes
+                .iter()
+                .filter(|node| node.visible)
+                .map(|node| node.outline.as_slice()),
+        );
+        assert_eq!(resource.iter_paths().count(), 2);
+
+        let mut buffer = FlatContoursBuffer::default();
+        let adapter = buffer.set_with_resource(&resource);
+        assert_eq!(buffer.ranges, vec![0..3, 3..7]);
+        assert_eq!(adapter.rect.max_x, 4.0);
+    }
+}
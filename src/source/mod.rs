@@ -1,5 +1,8 @@
+pub mod adapter;
+pub mod borrowed;
 pub mod buffer;
 pub mod contour;
+pub mod iter;
 pub mod resource;
 pub mod shape;
 pub mod shapes;
@@ -9,7 +9,7 @@ pub struct ShapeResourceIterator<'a, P> {
 
 impl<'a, P> ShapeResourceIterator<'a, P> {
     #[inline]
-    fn with_slice(slice: &'a [Vec<P>]) -> Self {
+    pub(crate) fn with_slice(slice: &'a [Vec<P>]) -> Self {
         Self { slice, index: 0 }
     }
 }
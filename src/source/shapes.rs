@@ -10,7 +10,7 @@ pub struct ShapesResourceIterator<'a, P> {
 
 impl<'a, P> ShapesResourceIterator<'a, P> {
     #[inline]
-    fn with_slice(slice: &'a [Vec<Vec<P>>]) -> Self {
+    pub(crate) fn with_slice(slice: &'a [Vec<Vec<P>>]) -> Self {
         Self { slice, i: 0, j: 0 }
     }
 }
@@ -1 +1,2 @@
 pub mod reserve;
+pub(crate) mod wide;
@@ -0,0 +1,106 @@
+use core::ops::AddAssign;
+
+/// A signed 256-bit integer used to accumulate exact sums of `i128` products.
+///
+/// The value is `hi * 2^128 + lo` in two's complement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct I256 {
+    hi: i128,
+    lo: u128,
+}
+
+impl I256 {
+    pub(crate) const ZERO: Self = Self { hi: 0, lo: 0 };
+
+    #[inline]
+    pub(crate) fn from_i128(value: i128) -> Self {
+        Self {
+            hi: if value < 0 { -1 } else { 0 },
+            lo: value as u128,
+        }
+    }
+
+    /// The exact product of two `i128` values.
+    #[inline]
+    pub(crate) fn mul(a: i128, b: i128) -> Self {
+        let (hi, lo) = mul_u128(a.unsigned_abs(), b.unsigned_abs());
+        let value = Self { hi: hi as i128, lo };
+        if (a < 0) != (b < 0) { value.neg() } else { value }
+    }
+
+    #[inline]
+    pub(crate) fn neg(self) -> Self {
+        // !x + 1
+        let (lo, carry) = (!self.lo).overflowing_add(1);
+        Self {
+            hi: (!self.hi).wrapping_add(carry as i128),
+            lo,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_negative(&self) -> bool {
+        self.hi < 0
+    }
+
+    #[inline]
+    pub(crate) fn to_f64(self) -> f64 {
+        const TWO_128: f64 = 340282366920938463463374607431768211456.0;
+        if self.is_negative() {
+            let abs = self.neg();
+            -(abs.hi as u128 as f64 * TWO_128 + abs.lo as f64)
+        } else {
+            self.hi as f64 * TWO_128 + self.lo as f64
+        }
+    }
+}
+
+impl AddAssign for I256 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        self.lo = lo;
+        self.hi = self.hi.wrapping_add(rhs.hi).wrapping_add(carry as i128);
+    }
+}
+
+/// The full 256-bit product as `(hi, lo)`.
+#[inline]
+fn mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::wide::I256;
+
+    #[test]
+    fn test_0() {
+        let max = i128::MAX;
+        let mut sum = I256::mul(max, max);
+        sum += I256::mul(-max, max);
+        assert_eq!(sum, I256::ZERO);
+
+        let mut sum = I256::from_i128(-5);
+        sum += I256::mul(2, 3);
+        assert_eq!(sum, I256::from_i128(1));
+        assert_eq!(I256::mul(-7, 3).to_f64(), -21.0);
+
+        let big = I256::mul(1 << 100, 1 << 100);
+        assert_eq!(big.to_f64(), 2f64.powi(200));
+        assert_eq!(big.neg().to_f64(), -(2f64.powi(200)));
+    }
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
+    use i_shape::flat::buffer::{FlatContoursBuffer, FlatShapesBuffer};
     use i_shape::int::path::ContourExtension;
-    use i_shape::int::simple::{SimpleContour, SimpleShape, SimpleShapes};
+    use i_shape::int::simple::{SimpleContour, SimpleShape, SimpleShapes, Simplify};
     use i_shape::{int_path, int_shape, int_shapes};
 
     #[test]
@@ -32,6 +33,26 @@ mod tests {
         assert_eq!(path, origin, "The path and origin are not equal!");
     }
 
+    #[test]
+    fn test_figure_eight() {
+        let mut contour = int_path![[0, 0], [2, 2], [2, 0], [0, 2],];
+        assert!(contour.is_clean());
+        assert!(!contour.is_simple());
+        assert!([contour.clone()].is_clean());
+        assert!(![contour.clone()].is_simple());
+
+        // the points are clean, so nothing is removed and the contour stays not simple
+        assert!(!contour.simplify_contour());
+        assert_eq!(contour.simplified().unwrap(), contour);
+        assert!(!contour.is_simple());
+
+        let touching = int_path![[0, 0], [4, 0], [2, 2], [4, 4], [0, 4], [2, 2],];
+        assert!(touching.is_clean());
+        assert!(!touching.is_simple());
+        assert!(int_path![[0, 0], [0, 1], [1, 1], [1, 0],].is_simple());
+        assert!(!int_path![[0, 0], [0, 1], [0, 2], [1, 1],].is_clean());
+    }
+
     #[test]
     fn test_degenerates_empty() {
         let incorrect = int_path![[0, 0], [0, 512], [0, 1],];
@@ -106,6 +127,34 @@ mod tests {
         assert!(shapes.simplified().is_empty());
     }
 
+    #[test]
+    fn test_degenerates_flat_shapes() {
+        let shapes = int_shapes![
+            [
+                [[-10, -10], [-10, 10], [10, 10], [-10, 10], [-10, -10], [10, -10],],
+                [[-5, -5], [5, -5], [5, 5], [-5, 5],],
+            ],
+            [
+                [[20, -10], [20, 0], [20, 10], [30, 10], [30, -10],],
+                [[22, 0], [24, 0], [26, 0],],
+                [[25, 5], [27, 5], [27, 7],],
+            ],
+        ];
+
+        let mut buffer = FlatShapesBuffer::default();
+        buffer.set_with_shapes(&shapes);
+        assert!(buffer.simplify_contour());
+        assert_eq!(buffer.to_shapes(), shapes.simplified());
+        assert_eq!(buffer.points.len(), 7);
+        assert!(!buffer.simplify_contour());
+
+        let mut buffer = FlatContoursBuffer::default();
+        buffer.set_with_shapes(&shapes);
+        assert!(buffer.simplify_contour());
+        assert_eq!(buffer.ranges.len(), 3);
+        assert_eq!(buffer.contour(0), int_path![[-5, -5], [5, -5], [5, 5], [-5, 5],]);
+    }
+
     #[test]
     fn test_area_1() {
         let mut path = int_path![[0, 0], [0, 1], [1, 1], [1, 0],];
@@ -123,70 +172,70 @@ mod tests {
     fn test_order_00() {
         let path = int_path![[-10, -10], [-10, 10], [10, 10], [10, -10],];
 
-        assert_eq!(path.is_clockwise_ordered(), true);
+        assert!(path.is_clockwise_ordered());
     }
 
     #[test]
     fn test_order_01() {
         let path = int_path![[-10, -10], [10, -10], [10, 10], [-10, 10],];
 
-        assert_eq!(path.is_clockwise_ordered(), false);
+        assert!(!path.is_clockwise_ordered());
     }
 
     #[test]
     fn test_convex_00() {
         let mut path = int_path![[-10, -10], [-10, 10], [10, 10], [10, -10],];
 
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
         path.reverse();
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
     }
 
     #[test]
     fn test_convex_01() {
         let mut path = int_path![[-10, -10], [0, 10], [10, -10], [0, -5],];
 
-        assert_eq!(path.is_convex(), false);
+        assert!(!path.is_convex());
         path.reverse();
-        assert_eq!(path.is_convex(), false);
+        assert!(!path.is_convex());
     }
 
     #[test]
     fn test_convex_02() {
         let mut path = int_path![[0, 0], [1, 2], [3, 3], [4, 1], [2, 0],];
 
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
         path.reverse();
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
     }
 
     #[test]
     fn test_convex_03() {
         let mut path = int_path![[0, 0], [1, 2], [0, 4], [4, 2], [2, 0],];
 
-        assert_eq!(path.is_convex(), false);
+        assert!(!path.is_convex());
         path.reverse();
-        assert_eq!(path.is_convex(), false);
+        assert!(!path.is_convex());
     }
 
     #[test]
     fn test_convex_04() {
         let path1 = int_path![[0, 0]];
 
-        assert_eq!(path1.is_convex(), true);
+        assert!(path1.is_convex());
 
         let path2 = int_path![[0, 0], [1, 0],];
 
-        assert_eq!(path2.is_convex(), true);
+        assert!(path2.is_convex());
     }
 
     #[test]
     fn test_convex_05() {
         let mut path = int_path![[0, 0], [1, 2], [2, 3], [3, 2], [4, 1], [2, 0],];
 
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
         path.reverse();
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
     }
 
     #[test]
@@ -202,9 +251,9 @@ mod tests {
             [0, -10],
         ];
 
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
         path.reverse();
-        assert_eq!(path.is_convex(), true);
+        assert!(path.is_convex());
     }
 
     #[test]
@@ -220,8 +269,8 @@ mod tests {
             [0, -10],
         ];
 
-        assert_eq!(path.is_convex(), false);
+        assert!(!path.is_convex());
         path.reverse();
-        assert_eq!(path.is_convex(), false);
+        assert!(!path.is_convex());
     }
 }